@@ -0,0 +1,149 @@
+use common::{
+    bench::{
+        self, BenchConfig,
+        history::{self, Change, History, HistoryEntry, RunInfo},
+    },
+    input, registry,
+};
+
+use crate::cli::{ArgParser, CliError, Selection, unexpected};
+
+pub fn main(args: &[String]) -> Result<(), CliError> {
+    let mut selection = Selection::default();
+    let mut config = BenchConfig::default();
+    let mut json = false;
+    let mut save = true;
+    let mut compare = false;
+    let mut baseline = None;
+    let mut history = History::new(History::default_path());
+
+    let mut args = ArgParser::new(args);
+    while let Some(arg) = args.next() {
+        match arg {
+            "--iterations" | "-n" => config.iterations = args.number(arg)?,
+            "--warmup" | "-w" => config.warmup = args.number(arg)?,
+            "--json" => json = true,
+            "--fresh" => config.always_fresh = true,
+            "--no-save" => save = false,
+            "--compare" => compare = true,
+            "--baseline" => {
+                compare = true;
+                baseline = Some(args.value(arg)?);
+            }
+            "--history" => history = History::new(args.value(arg)?),
+            _ if selection.parse_arg(arg, &mut args)? => {}
+            _ => return Err(unexpected(arg)),
+        }
+    }
+
+    if config.iterations == 0 {
+        return Err(CliError::Usage(
+            "`--iterations` must be at least 1".to_owned(),
+        ));
+    }
+
+    let day = selection.validate()?;
+    let (input, source) = selection.read_input(day)?;
+
+    let run = RunInfo::now(&input::workspace_dir());
+    let mut entries = Vec::new();
+
+    for part in selection.parts() {
+        let entry = registry::find(day, part)
+            .ok_or_else(|| format!("day {day} part {part} is not implemented"))?;
+
+        let result = bench::bench(entry, &input, &config).map_err(|error| {
+            format!(
+                "failed to parse {source}: {error}\n{}",
+                error.snippet(&input)
+            )
+        })?;
+
+        if json {
+            println!("{}", result.to_json());
+        } else {
+            println!(
+                "Day {day} part {part} ({}, {} iterations{})\n  parse: {}\n  solve: {}",
+                result.name,
+                config.iterations,
+                if result.fresh { ", fresh state" } else { "" },
+                result.parse,
+                result.solve
+            );
+        }
+
+        entries.extend(HistoryEntry::from_result(&run, &result));
+    }
+
+    // Load before saving so the current run can't become its own baseline.
+    let previous = if compare {
+        history.load().map_err(|error| error.to_string())?
+    } else {
+        Vec::new()
+    };
+
+    if save {
+        history
+            .append(&entries)
+            .map_err(|error| error.to_string())?;
+    }
+
+    if compare {
+        let regressions = report_comparison(&previous, &entries, baseline, json);
+        if regressions > 0 {
+            return Err(format!("{regressions} regression(s) detected").into());
+        }
+    }
+
+    Ok(())
+}
+
+/// Prints how each of `entries` compares to its baseline in `previous`,
+/// returning the number of regressions. With `json` the comparison goes to
+/// standard error, so standard output only holds the JSON results.
+fn report_comparison(
+    previous: &[HistoryEntry],
+    entries: &[HistoryEntry],
+    baseline: Option<&str>,
+    json: bool,
+) -> usize {
+    let print = |line: &str| {
+        if json {
+            eprintln!("{line}");
+        } else {
+            println!("{line}");
+        }
+    };
+    let mut regressions = 0;
+
+    print("");
+    for entry in entries {
+        let label = format!("Day {} part {} {}", entry.day, entry.part, entry.phase);
+
+        let Some(base) = history::find_baseline(previous, entry, baseline) else {
+            print(&format!("{label}: no baseline to compare against"));
+            continue;
+        };
+
+        let comparison = history::compare(base, entry);
+        let verdict = match comparison.verdict {
+            Change::Regression => {
+                regressions += 1;
+                "REGRESSION"
+            }
+            Change::Improvement => "improvement",
+            Change::NoChange => "no change",
+        };
+
+        print(&format!(
+            "{label}: median {} vs {} at {} ({:+.1}%, p = {:.4}) {verdict}",
+            bench::format_duration(std::time::Duration::from_nanos(entry.median_ns)),
+            bench::format_duration(std::time::Duration::from_nanos(base.median_ns)),
+            base.revision,
+            comparison.change * 100.0,
+            comparison.p_value,
+        ));
+    }
+
+    regressions
+}
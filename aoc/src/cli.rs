@@ -0,0 +1,138 @@
+use common::{
+    calendar::{Calendar, Clock, Unavailable},
+    config::Config,
+    fetch,
+    input::{self, InputDir},
+};
+
+/// Why a command failed. Usage errors are followed by the usage text.
+pub enum CliError {
+    Usage(String),
+    Failed(String),
+}
+
+impl From<String> for CliError {
+    fn from(error: String) -> Self {
+        CliError::Failed(error)
+    }
+}
+
+/// Walks the arguments of a single command.
+pub struct ArgParser<'a> {
+    args: std::slice::Iter<'a, String>,
+}
+
+impl<'a> ArgParser<'a> {
+    pub fn new(args: &'a [String]) -> Self {
+        Self { args: args.iter() }
+    }
+
+    pub fn next(&mut self) -> Option<&'a str> {
+        self.args.next().map(String::as_str)
+    }
+
+    /// Returns the value following `flag`.
+    pub fn value(&mut self, flag: &str) -> Result<&'a str, CliError> {
+        self.next()
+            .ok_or_else(|| CliError::Usage(format!("`{flag}` requires a value")))
+    }
+
+    /// Returns the value following `flag` parsed as a number.
+    pub fn number(&mut self, flag: &str) -> Result<usize, CliError> {
+        parse_number(self.value(flag)?, flag)
+    }
+}
+
+pub fn parse_number(value: &str, name: &str) -> Result<usize, CliError> {
+    value
+        .parse()
+        .map_err(|_| CliError::Usage(format!("invalid {name} `{value}`, expected a number")))
+}
+
+/// The day, parts and input a command should work on.
+#[derive(Default)]
+pub struct Selection {
+    pub day: Option<usize>,
+    pub part: Option<usize>,
+    pub input: Option<String>,
+    pub set: Option<String>,
+}
+
+impl Selection {
+    /// Consumes `arg` if it's one of the selection arguments, returning
+    /// whether it was.
+    pub fn parse_arg(&mut self, arg: &str, args: &mut ArgParser) -> Result<bool, CliError> {
+        match arg {
+            "--part" | "-p" => self.part = Some(args.number(arg)?),
+            "--input" | "-i" => self.input = Some(args.value(arg)?.to_owned()),
+            "--set" | "-s" => self.set = Some(args.value(arg)?.to_owned()),
+            value if self.day.is_none() && !value.starts_with('-') => {
+                self.day = Some(parse_number(value, "day")?);
+            }
+            _ => return Ok(false),
+        }
+
+        Ok(true)
+    }
+
+    /// Ensures the selection is complete and consistent.
+    pub fn validate(&self) -> Result<usize, CliError> {
+        if self.input.is_some() && self.set.is_some() {
+            return Err(CliError::Usage(
+                "`--input` and `--set` can't be used together".to_owned(),
+            ));
+        }
+
+        self.day
+            .ok_or_else(|| CliError::Usage("missing <day> argument".to_owned()))
+    }
+
+    pub fn parts(&self) -> Vec<usize> {
+        match self.part {
+            Some(part) => vec![part],
+            None => vec![1, 2],
+        }
+    }
+
+    /// Reads the selected input, returning it along with a description of
+    /// where it came from.
+    pub fn read_input(&self, day: usize) -> Result<(String, String), CliError> {
+        let (input, source) = match &self.input {
+            Some(source) => (input::read_source(source), source.clone()),
+            None => {
+                let set = self.set.as_deref().unwrap_or("input");
+                (
+                    InputDir::for_day(day).read(set),
+                    format!("day {day} input set `{set}`"),
+                )
+            }
+        };
+
+        Ok((input.map_err(|error| error.to_string())?, source))
+    }
+}
+
+pub fn unexpected(arg: &str) -> CliError {
+    CliError::Usage(format!("unexpected argument `{arg}`"))
+}
+
+/// Loads the configuration of the workspace, see `aoc.toml`.
+pub fn load_config() -> Result<Config, CliError> {
+    Config::load(&Config::default_path()).map_err(|error| CliError::Failed(error.to_string()))
+}
+
+/// The calendar of the configured year.
+pub fn calendar(config: &Config) -> Calendar {
+    Calendar::new(config.client.year.unwrap_or(fetch::DEFAULT_YEAR))
+}
+
+/// Fails unless `day` is part of the calendar and its puzzle is unlocked,
+/// telling how long until it is.
+pub fn require_unlocked(calendar: &Calendar<impl Clock>, day: usize) -> Result<(), CliError> {
+    calendar.check_unlocked(day).map_err(|error| match error {
+        Unavailable::NoSuchDay(_) => CliError::Usage(error.to_string()),
+        Unavailable::Locked { .. } => {
+            CliError::Failed(format!("{error}, at midnight US Eastern time"))
+        }
+    })
+}
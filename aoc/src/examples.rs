@@ -0,0 +1,144 @@
+use common::{
+    Answer,
+    input::InputDir,
+    puzzle::{Example, Puzzle},
+};
+
+use crate::{
+    cli::{ArgParser, CliError, parse_number, unexpected},
+    new::{edit, write},
+    read,
+    submit::record_answer,
+};
+
+/// Lines of each block shown by `--list`.
+const PREVIEW_LINES: usize = 4;
+
+pub fn main(args: &[String]) -> Result<(), CliError> {
+    let mut day = None;
+    let mut part = None;
+    let mut block = None;
+    let mut page = None;
+    let mut list = false;
+    let mut force = false;
+    let mut refresh = false;
+
+    let mut args = ArgParser::new(args);
+    while let Some(arg) = args.next() {
+        match arg {
+            "--part" | "-p" => part = Some(args.number(arg)?),
+            "--block" | "-b" => block = Some(args.number(arg)?),
+            "--page" => page = Some(args.value(arg)?),
+            "--list" | "-l" => list = true,
+            "--refresh" | "-r" => refresh = true,
+            "--force" | "-f" => force = true,
+            value if day.is_none() && !value.starts_with('-') => {
+                day = Some(parse_number(value, "day")?);
+            }
+            _ => return Err(unexpected(arg)),
+        }
+    }
+
+    let day = day.ok_or_else(|| CliError::Usage("missing <day> argument".to_owned()))?;
+    if block.is_some() && part.is_none() {
+        return Err(CliError::Usage("`--block` requires `--part`".to_owned()));
+    }
+    if block == Some(0) {
+        return Err(CliError::Usage("blocks are numbered from 1".to_owned()));
+    }
+
+    let html = match page {
+        Some(path) => std::fs::read_to_string(path)
+            .map_err(|error| format!("failed to read {path}: {error}"))?,
+        None => read::description(&read::client()?, day, refresh)?,
+    };
+
+    let puzzle = Puzzle::parse(&html);
+    if list {
+        print_blocks(&puzzle);
+        return Ok(());
+    }
+
+    let parts = match part {
+        Some(part) => vec![part],
+        None => (1..=puzzle.parts.len().min(2)).collect(),
+    };
+
+    let inputs = InputDir::for_day(day);
+    for part in parts {
+        let example = puzzle
+            .example(part, block.map(|block| block - 1))
+            .map_err(|error| error.to_string())?;
+
+        write_example(&inputs, part, &example, force)?;
+    }
+
+    Ok(())
+}
+
+/// Writes the example input of `part` and records its answer.
+fn write_example(
+    inputs: &InputDir,
+    part: usize,
+    example: &Example,
+    force: bool,
+) -> Result<(), CliError> {
+    let set = format!("example_input_p{part}");
+    let path = inputs.crate_dir().join("res/inputs").join(&set);
+
+    if path.exists() && !force {
+        println!(
+            "Part {part}: {} already exists, use --force to overwrite it",
+            path.display()
+        );
+        return Ok(());
+    }
+
+    write(&path, &example.input)?;
+
+    let Some(answer) = &example.answer else {
+        println!(
+            "Part {part}: wrote block {} to {}, but found no answer, add it to res/answers.toml",
+            example.block + 1,
+            path.display()
+        );
+        return Ok(());
+    };
+
+    let Ok(parsed) = answer.parse::<Answer>();
+    edit(&inputs.crate_dir().join("res/answers.toml"), |manifest| {
+        record_answer(manifest, &set, part, &parsed)
+    })?;
+
+    println!(
+        "Part {part}: wrote block {} to {} with answer {answer}",
+        example.block + 1,
+        path.display()
+    );
+
+    Ok(())
+}
+
+/// Prints the start of every block along with the part it's in, and the
+/// answers found for each part.
+fn print_blocks(puzzle: &Puzzle) {
+    for (index, part) in puzzle.parts.iter().enumerate() {
+        println!(
+            "Part {}: answer {}",
+            index + 1,
+            part.emphasized.last().map_or("not found", String::as_str)
+        );
+
+        for &block in &part.blocks {
+            println!("  Block {}:", block + 1);
+
+            let lines = puzzle.blocks[block].lines().collect::<Vec<_>>();
+            for line in lines.iter().take(PREVIEW_LINES) {
+                println!("    {line}");
+            }
+            if lines.len() > PREVIEW_LINES {
+                println!("    ({} more lines)", lines.len() - PREVIEW_LINES);
+            }
+        }
+    }
+}
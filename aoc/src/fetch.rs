@@ -0,0 +1,39 @@
+use common::{
+    fetch::{Client, Fetched},
+    input::InputDir,
+};
+
+use crate::cli::{
+    self, ArgParser, CliError, load_config, parse_number, require_unlocked, unexpected,
+};
+
+pub fn main(args: &[String]) -> Result<(), CliError> {
+    let mut day = None;
+
+    let mut args = ArgParser::new(args);
+    while let Some(arg) = args.next() {
+        match arg {
+            value if day.is_none() && !value.starts_with('-') => {
+                day = Some(parse_number(value, "day")?);
+            }
+            _ => return Err(unexpected(arg)),
+        }
+    }
+
+    let day = day.ok_or_else(|| CliError::Usage("missing <day> argument".to_owned()))?;
+
+    let config = load_config()?;
+    require_unlocked(&cli::calendar(&config), day)?;
+
+    let client = Client::from_config(&config.client);
+
+    match client
+        .fetch_input(day, &InputDir::for_day(day))
+        .map_err(|error| error.to_string())?
+    {
+        Fetched::Cached(path) => println!("Already downloaded to {}", path.display()),
+        Fetched::Downloaded(path) => println!("Downloaded to {}", path.display()),
+    }
+
+    Ok(())
+}
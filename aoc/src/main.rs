@@ -0,0 +1,129 @@
+#![allow(clippy::cargo_common_metadata)]
+
+mod bench;
+mod cli;
+mod examples;
+mod fetch;
+mod new;
+mod read;
+mod run;
+mod solvers;
+mod status;
+mod submit;
+mod table;
+
+use std::process::ExitCode;
+
+use cli::CliError;
+
+const USAGE: &str = "\
+Usage: aoc <command> [options]
+
+Commands:
+    run <day> [--part <part>] [--input <path> | --set <name>]
+        Solves the given day (both parts unless --part is given) and prints
+        the answers along with solve timings. The input is parsed once and
+        shared by both parts, so there's a single parse timing. It's read
+        from <path>, or standard input if <path> is `-`, and otherwise from
+        the input set <name> (`input` by default) in $AOC_INPUT_DIR/day-<day>/
+        or days/day-<day>/res/inputs/.
+
+    run --all [--part <part>] [--set <name>] [--jobs <n>] [--budget <ms>]
+        Solves every day on <n> threads and prints a table of the answers,
+        whether they match res/answers.toml, their timings and their share
+        of the runtime budget. Fails if any answer is wrong, any input fails
+        to parse or a budget is exceeded, days without the input set are
+        skipped and placeholder solvers are shown as unsolved. Budgets are
+        set in aoc.toml, --budget overrides the total one. <n> defaults to
+        the number of CPUs, but budgets assume days are solved one at a time,
+        so use --jobs 1 when checking them.
+
+    bench <day> [--part <part>] [--input <path> | --set <name>]
+              [--iterations <n>] [--warmup <n>] [--fresh] [--json]
+              [--compare] [--baseline <revision>] [--no-save] [--history <path>]
+        Benchmarks parsing and solving separately, reporting the minimum,
+        median and 95th percentile of <n> iterations (100 by default) after
+        a warmup (10 iterations by default). Solvers that modify their parsed
+        input get a fresh copy of it before every solve, --fresh does so for
+        all solvers. --json prints one JSON object per part instead.
+        Results are appended to the history file (bench_history.jsonl in the
+        workspace root by default) unless --no-save is given. --compare
+        compares them against the previous run, or the latest run on
+        <revision> with --baseline, and fails on significant regressions.
+        With --json the comparison is printed to standard error.
+
+    new <day> [--force]
+        Creates the crate for the given day in days/day-<day>/ from a
+        template, adds it to the workspace and links it into the runner. Its
+        solvers only return a placeholder, which isn't submitted and is shown
+        as unsolved, until their PLACEHOLDER constant is removed. An existing
+        crate is only overwritten with --force, its inputs in res/inputs/ and
+        answers in res/answers.toml are kept.
+
+    fetch <day>
+        Downloads the input of the given day to the input set `input`, unless
+        it already exists. Requires the session cookie of a logged in account
+        in $AOC_SESSION or `client.session` in aoc.toml.
+
+    submit <day> <part>
+        Solves the given part with the input set `input` and submits the
+        answer. Answers known to be wrong from the guess log of the day
+        (res/guesses.jsonl), or out of the too high/too low bounds learned
+        from it, are refused. A correct answer is written to
+        res/answers.toml. Fails unless the answer is correct.
+
+    examples <day> [--part <part> [--block <n>]] [--page <path>] [--list]
+                   [--force] [--refresh]
+        Writes the example input of each part described on the page of the
+        given day to res/inputs/example_input_p<part>, and its answer to
+        res/answers.toml. The page is read from <path>, e.g. one saved from
+        the browser, or else the archived description (see `read`). The
+        example is the first code block of the part (or the first part),
+        --block picks another one, --list shows the blocks and the answers
+        found. Existing examples are only overwritten with --force.
+
+    read <day> [--refresh] [--width <n>]
+        Prints the description of the given day, wrapped to <n> columns (80
+        by default). It's archived in res/description.html of the day on
+        first read, and downloaded again with --refresh, e.g. to get the
+        second part once the first one is solved.
+
+    status
+        Shows which days are unlocked, with a countdown to the next one, and
+        for each of them whether its crate, input and examples exist and
+        which parts are solved. Puzzles unlock at midnight US Eastern time,
+        `new`, `fetch`, `submit`, `read` and `examples` refuse days that
+        aren't unlocked yet.";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    let result = match args.first().map(String::as_str) {
+        Some("run") => run::main(&args[1..]),
+        Some("bench") => bench::main(&args[1..]),
+        Some("new") => new::main(&args[1..]),
+        Some("fetch") => fetch::main(&args[1..]),
+        Some("submit") => submit::main(&args[1..]),
+        Some("examples") => examples::main(&args[1..]),
+        Some("read") => read::main(&args[1..]),
+        Some("status") => status::main(&args[1..]),
+        Some("help" | "-h" | "--help") => {
+            println!("{USAGE}");
+            Ok(())
+        }
+        Some(command) => Err(CliError::Usage(format!("unknown command `{command}`"))),
+        None => Err(CliError::Usage("no command given".to_owned())),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(CliError::Usage(error)) => {
+            eprintln!("error: {error}\n\n{USAGE}");
+            ExitCode::FAILURE
+        }
+        Err(CliError::Failed(error)) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,255 @@
+use std::{fs, path::Path};
+
+use common::input;
+use toml_edit::{DocumentMut, InlineTable, Value};
+
+use crate::cli::{
+    self, ArgParser, CliError, load_config, parse_number, require_unlocked, unexpected,
+};
+
+/// Manifest of the expected answers, relative to the directory of a day crate.
+const ANSWERS: &str = "res/answers.toml";
+
+/// Files of a new day crate, relative to its directory, and their templates.
+/// `{day}` is replaced with the number of the day.
+const TEMPLATES: [(&str, &str); 4] = [
+    (
+        "Cargo.toml",
+        include_str!("../templates/day/Cargo.toml.template"),
+    ),
+    (
+        "build.rs",
+        include_str!("../templates/day/build.rs.template"),
+    ),
+    (
+        "src/lib.rs",
+        include_str!("../templates/day/lib.rs.template"),
+    ),
+    (
+        ANSWERS,
+        include_str!("../templates/day/answers.toml.template"),
+    ),
+];
+
+pub fn main(args: &[String]) -> Result<(), CliError> {
+    let mut day = None;
+    let mut force = false;
+
+    let mut args = ArgParser::new(args);
+    while let Some(arg) = args.next() {
+        match arg {
+            "--force" | "-f" => force = true,
+            value if day.is_none() && !value.starts_with('-') => {
+                day = Some(parse_number(value, "day")?);
+            }
+            _ => return Err(unexpected(arg)),
+        }
+    }
+
+    let day = day.ok_or_else(|| CliError::Usage("missing <day> argument".to_owned()))?;
+    require_unlocked(&cli::calendar(&load_config()?), day)?;
+
+    let workspace = input::workspace_dir();
+    let crate_dir = workspace.join(format!("days/day-{day}"));
+
+    // `aoc fetch` and `aoc read` may have created `res/` of a day without a
+    // crate already, only an existing manifest means it's been created.
+    if crate_dir.join("Cargo.toml").exists() && !force {
+        return Err(format!(
+            "{} already exists, use --force to overwrite it",
+            crate_dir.display()
+        )
+        .into());
+    }
+
+    for (path, template) in TEMPLATES {
+        let path = crate_dir.join(path);
+
+        // Recorded answers are kept like the inputs.
+        if path.ends_with(ANSWERS) && path.exists() {
+            continue;
+        }
+
+        write(&path, &template.replace("{day}", &day.to_string()))?;
+    }
+
+    // Keeps the otherwise empty directory in git until the inputs are added.
+    write(&crate_dir.join("res/inputs/.gitkeep"), "")?;
+
+    edit(&workspace.join("Cargo.toml"), |manifest| {
+        add_member(manifest, day)
+    })?;
+    edit(&workspace.join("aoc/Cargo.toml"), |manifest| {
+        add_dependency(manifest, day)
+    })?;
+    edit(&workspace.join("aoc/src/solvers.rs"), |source| {
+        link_day(source, day)
+    })?;
+
+    println!(
+        "Created day {day} in {}, add its inputs to res/inputs/ and answers to res/answers.toml, \
+         and remove the PLACEHOLDER constant of each solver once it's solved",
+        crate_dir.display()
+    );
+
+    Ok(())
+}
+
+pub fn write(path: &Path, contents: &str) -> Result<(), CliError> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|error| format!("failed to create {}: {error}", parent.display()))?;
+    }
+
+    fs::write(path, contents)
+        .map_err(|error| CliError::Failed(format!("failed to write {}: {error}", path.display())))
+}
+
+/// Rewrites the file at `path` with `change` applied to its contents.
+pub fn edit(
+    path: &Path,
+    change: impl FnOnce(&str) -> Result<String, String>,
+) -> Result<(), CliError> {
+    let contents = fs::read_to_string(path)
+        .map_err(|error| format!("failed to read {}: {error}", path.display()))?;
+    let changed = change(&contents).map_err(|error| format!("{}: {error}", path.display()))?;
+
+    if changed != contents {
+        write(path, &changed)?;
+    }
+
+    Ok(())
+}
+
+/// Returns the number of the day crate at `path`, e.g. 4 for `days/day-4`.
+fn day_of_member(path: &str) -> Option<usize> {
+    path.strip_prefix("days/day-")?.parse().ok()
+}
+
+/// Adds the crate of `day` to the workspace members in `manifest`, keeping
+/// the days in order.
+fn add_member(manifest: &str, day: usize) -> Result<String, String> {
+    let mut document = manifest
+        .parse::<DocumentMut>()
+        .map_err(|error| error.to_string())?;
+    let members = document
+        .get_mut("workspace")
+        .and_then(|workspace| workspace.get_mut("members"))
+        .and_then(|members| members.as_array_mut())
+        .ok_or("missing `workspace.members` array")?;
+
+    let days = members
+        .iter()
+        .map(|member| member.as_str().and_then(day_of_member))
+        .collect::<Vec<_>>();
+
+    if days.contains(&Some(day)) {
+        return Ok(manifest.to_owned());
+    }
+
+    // After the last day before this one, or after every other member.
+    let index = days
+        .iter()
+        .rposition(|other| other.is_some_and(|other| other < day))
+        .or_else(|| days.iter().rposition(Option::is_none))
+        .map_or(0, |index| index + 1);
+
+    // Format like the member it follows instead of inheriting its neighbour's
+    // surrounding whitespace.
+    let decor = members
+        .get(index.saturating_sub(1))
+        .map(|member| member.decor().clone())
+        .unwrap_or_default();
+
+    members.insert(index, format!("days/day-{day}"));
+    if let Some(member) = members.get_mut(index) {
+        *member.decor_mut() = decor;
+    }
+
+    Ok(document.to_string())
+}
+
+/// Adds the crate of `day` to the dependencies of the runner in `manifest`.
+fn add_dependency(manifest: &str, day: usize) -> Result<String, String> {
+    let mut document = manifest
+        .parse::<DocumentMut>()
+        .map_err(|error| error.to_string())?;
+    let dependencies = document
+        .get_mut("dependencies")
+        .and_then(|dependencies| dependencies.as_table_mut())
+        .ok_or("missing `dependencies` table")?;
+
+    let name = format!("day-{day}");
+    if !dependencies.contains_key(&name) {
+        let mut dependency = InlineTable::new();
+        dependency.insert("path", Value::from(format!("../days/day-{day}")));
+        dependencies.insert(&name, toml_edit::value(dependency));
+    }
+
+    Ok(document.to_string())
+}
+
+/// Adds a `use day_N as _;` for `day` to `source`, keeping the days in order,
+/// so its solvers are linked into the runner.
+fn link_day(source: &str, day: usize) -> Result<String, String> {
+    let line_day = |line: &str| {
+        line.strip_prefix("use day_")?
+            .strip_suffix(" as _;")?
+            .parse::<usize>()
+            .ok()
+    };
+
+    let mut lines = source.lines().collect::<Vec<_>>();
+    if lines.iter().any(|line| line_day(line) == Some(day)) {
+        return Ok(source.to_owned());
+    }
+
+    let last = lines
+        .iter()
+        .rposition(|line| line_day(line).is_some())
+        .ok_or("no `use day_<n> as _;` to add the day next to")?;
+
+    let index = lines
+        .iter()
+        .position(|line| line_day(line).is_some_and(|other| other > day))
+        .unwrap_or(last + 1);
+
+    let line = format!("use day_{day} as _;");
+    lines.insert(index, &line);
+
+    let mut source = lines.join("\n");
+    source.push('\n');
+    Ok(source)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_member_in_order() {
+        let manifest = "[workspace]\nmembers = [\n    \"common\",\n    \"days/day-1\",\n    \"days/day-3\",\n]\n";
+
+        assert_eq!(
+            add_member(manifest, 2).unwrap(),
+            "[workspace]\nmembers = [\n    \"common\",\n    \"days/day-1\",\n    \"days/day-2\",\n    \"days/day-3\",\n]\n"
+        );
+        assert_eq!(add_member(manifest, 3).unwrap(), manifest);
+    }
+
+    #[test]
+    fn test_link_day_in_order() {
+        let source = "use common;\n\nuse day_1 as _;\nuse day_3 as _;\n\nfn main() {}\n";
+
+        assert_eq!(
+            link_day(source, 2).unwrap(),
+            "use common;\n\nuse day_1 as _;\nuse day_2 as _;\nuse day_3 as _;\n\nfn main() {}\n"
+        );
+        assert_eq!(
+            link_day(source, 4).unwrap(),
+            "use common;\n\nuse day_1 as _;\nuse day_3 as _;\nuse day_4 as _;\n\nfn main() {}\n"
+        );
+        assert_eq!(link_day(source, 1).unwrap(), source);
+        assert!(link_day("fn main() {}\n", 1).is_err());
+    }
+}
@@ -0,0 +1,85 @@
+use std::io::IsTerminal;
+
+use common::{
+    calendar::Calendar,
+    fetch::Client,
+    input::InputDir,
+    puzzle::{
+        self,
+        render::{self, Style},
+    },
+};
+
+use crate::cli::{ArgParser, CliError, load_config, parse_number, require_unlocked, unexpected};
+
+pub fn main(args: &[String]) -> Result<(), CliError> {
+    let mut day = None;
+    let mut refresh = false;
+    let mut width = None;
+
+    let mut args = ArgParser::new(args);
+    while let Some(arg) = args.next() {
+        match arg {
+            "--refresh" | "-r" => refresh = true,
+            "--width" | "-w" => width = Some(args.number(arg)?),
+            value if day.is_none() && !value.starts_with('-') => {
+                day = Some(parse_number(value, "day")?);
+            }
+            _ => return Err(unexpected(arg)),
+        }
+    }
+
+    let day = day.ok_or_else(|| CliError::Usage("missing <day> argument".to_owned()))?;
+    let client = client()?;
+    let html = description(&client, day, refresh)?;
+
+    let stdout = std::io::stdout();
+    let style = Style {
+        width: width.unwrap_or(Style::default().width),
+        ansi: stdout.is_terminal() && std::env::var_os("NO_COLOR").is_none(),
+    };
+
+    print!("{}", render::render(&html, client.base_url(), style));
+
+    if puzzle::Puzzle::parse(&html).parts.len() < 2 {
+        println!("\n(Part 2 shows up once part 1 is solved, get it with --refresh.)");
+    }
+
+    Ok(())
+}
+
+pub fn client() -> Result<Client, CliError> {
+    Ok(Client::from_config(&load_config()?.client))
+}
+
+/// Reads the archived description of `day`, downloading it first if it isn't
+/// archived yet or `refresh` is set. Fails without asking the website if the
+/// puzzle isn't unlocked yet.
+pub fn description(client: &Client, day: usize, refresh: bool) -> Result<String, CliError> {
+    require_unlocked(&Calendar::new(client.year()), day)?;
+
+    let path = puzzle::archive_path(InputDir::for_day(day).crate_dir());
+
+    client
+        .fetch_puzzle(day, &path, refresh)
+        .map_err(|error| error.to_string())?;
+
+    std::fs::read_to_string(&path)
+        .map_err(|error| CliError::Failed(format!("failed to read {}: {error}", path.display())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_description_of_locked_day() {
+        // Nothing listens there, so asking the website would fail differently.
+        let client = Client::new("http://127.0.0.1:1", 2999, Some("secret".to_owned()));
+
+        let Err(CliError::Failed(error)) = description(&client, 1, true) else {
+            panic!("expected the locked day to be refused");
+        };
+        assert!(error.starts_with("day 1 unlocks in "), "{error}");
+    }
+}
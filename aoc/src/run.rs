@@ -0,0 +1,360 @@
+use std::{
+    collections::BTreeMap,
+    panic::{self, AssertUnwindSafe},
+    time::{Duration, Instant},
+};
+
+use common::{
+    Answer,
+    answers::Answers,
+    bench::format_duration,
+    input::{InputDir, InputError},
+    registry::{self, SolverEntry},
+};
+
+use crate::{
+    cli::{ArgParser, CliError, Selection, load_config, unexpected},
+    solvers::{self, DayReport},
+    table::{Align, Table},
+};
+
+pub fn main(args: &[String]) -> Result<(), CliError> {
+    let mut selection = Selection::default();
+    let mut all = false;
+    let mut jobs = None;
+    let mut budget = None;
+
+    let mut args = ArgParser::new(args);
+    while let Some(arg) = args.next() {
+        match arg {
+            "--all" | "-a" => all = true,
+            "--jobs" | "-j" => jobs = Some(args.number(arg)?),
+            "--budget" => budget = Some(args.number(arg)?),
+            _ if selection.parse_arg(arg, &mut args)? => {}
+            _ => return Err(unexpected(arg)),
+        }
+    }
+
+    if all {
+        return run_all(&selection, jobs, budget);
+    }
+
+    if jobs.is_some() || budget.is_some() {
+        return Err(CliError::Usage(
+            "`--jobs` and `--budget` require `--all`".to_owned(),
+        ));
+    }
+
+    let day = selection.validate()?;
+    let (input, source) = selection.read_input(day)?;
+
+    let entries = selection
+        .parts()
+        .into_iter()
+        .map(|part| {
+            registry::find(day, part)
+                .ok_or_else(|| format!("day {day} part {part} is not implemented"))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let report = solvers::run_day(&entries, &input).map_err(|error| {
+        format!(
+            "failed to parse {source}: {error}\n{}",
+            error.snippet(&input)
+        )
+    })?;
+
+    println!("Day {day} (parse: {})", format_duration(report.parse_time));
+
+    for part in report.parts {
+        match part.parse_time {
+            Some(parse_time) => println!(
+                "  Part {}: {} (parse: {}, solve: {})",
+                part.part,
+                part.answer,
+                format_duration(parse_time),
+                format_duration(part.solve_time),
+            ),
+            None => println!(
+                "  Part {}: {} (solve: {})",
+                part.part,
+                part.answer,
+                format_duration(part.solve_time),
+            ),
+        }
+    }
+
+    Ok(())
+}
+
+/// What became of running the solvers of a single day.
+enum Outcome {
+    Solved(DayReport, Answers),
+    MissingInput,
+    Failed(String),
+}
+
+/// Runs every registered solver, a day per job, and prints a summary table.
+/// `total_budget` in milliseconds overrides the one in the configuration.
+fn run_all(
+    selection: &Selection,
+    jobs: Option<usize>,
+    total_budget: Option<usize>,
+) -> Result<(), CliError> {
+    if selection.day.is_some() {
+        return Err(CliError::Usage(
+            "`--all` can't be used with a <day>".to_owned(),
+        ));
+    }
+
+    if selection.input.is_some() {
+        return Err(CliError::Usage(
+            "`--all` can't be used with `--input`".to_owned(),
+        ));
+    }
+
+    let set = selection.set.as_deref().unwrap_or("input");
+
+    let mut budget = load_config()?.budget;
+    if let Some(total_budget) = total_budget {
+        #[allow(clippy::cast_precision_loss)]
+        let total_budget = total_budget as f64;
+        budget.total_ms = Some(total_budget);
+    }
+
+    let jobs = match jobs {
+        Some(0) => return Err(CliError::Usage("`--jobs` must be at least 1".to_owned())),
+        Some(jobs) => jobs,
+        None => std::thread::available_parallelism().map_or(1, usize::from),
+    };
+
+    // Days solved in parallel compete for the CPU, so whether they stay
+    // within their budgets depends on the number of jobs and the load of
+    // the machine. Budgets are meant for days solved one at a time.
+    if jobs > 1 && !budget.is_empty() {
+        eprintln!("note: budgets assume --jobs 1, timings of {jobs} jobs may exceed them");
+    }
+
+    let mut days = BTreeMap::<usize, Vec<&SolverEntry>>::new();
+    let mut unsolved = BTreeMap::<usize, Vec<usize>>::new();
+    for entry in registry::solvers() {
+        if selection.part.is_some_and(|part| part != entry.part) {
+            continue;
+        }
+
+        let solvers = days.entry(entry.day).or_default();
+        if entry.placeholder {
+            unsolved.entry(entry.day).or_default().push(entry.part);
+        } else {
+            solvers.push(entry);
+        }
+    }
+
+    let start = Instant::now();
+    let outcomes = solvers::run_parallel(days.into_iter().collect(), jobs, |(day, entries)| {
+        // A panicking solver shouldn't take the other days down with it.
+        let outcome = panic::catch_unwind(AssertUnwindSafe(|| run_one(day, &entries, set)))
+            .unwrap_or_else(|_| Outcome::Failed("solver panicked".to_owned()));
+
+        (day, outcome)
+    });
+    let wall_time = start.elapsed();
+
+    let total_time = outcomes
+        .iter()
+        .filter_map(|(_, outcome)| match outcome {
+            Outcome::Solved(report, _) => Some(report.total_time()),
+            _ => None,
+        })
+        .sum::<Duration>();
+
+    // Shares are of the budget, or of the total time without one.
+    let total_budget = budget.total();
+    let share = |time: Duration| {
+        let whole = total_budget.unwrap_or(total_time).as_secs_f64();
+        if whole > 0.0 {
+            format!("{:.1}%", 100.0 * time.as_secs_f64() / whole)
+        } else {
+            String::new()
+        }
+    };
+
+    let mut table = Table::new(&[
+        ("Day", Align::Right),
+        ("Part", Align::Right),
+        ("Answer", Align::Left),
+        ("Verdict", Align::Left),
+        ("Parse", Align::Right),
+        ("Solve", Align::Right),
+        ("Share", Align::Right),
+    ]);
+
+    let mut total_parse = Duration::ZERO;
+    let mut total_solve = Duration::ZERO;
+    let mut failures = 0;
+    let mut over_budget = Vec::new();
+
+    for (day, outcome) in outcomes {
+        let (report, answers) = match outcome {
+            Outcome::Solved(report, answers) => (report, answers),
+            Outcome::MissingInput => {
+                table.push(vec![
+                    day.to_string(),
+                    String::new(),
+                    String::new(),
+                    format!("missing input `{set}`"),
+                ]);
+                continue;
+            }
+            Outcome::Failed(error) => {
+                failures += 1;
+                // Only the first line fits in the table, e.g. of a TOML error.
+                let error = error.lines().next().unwrap_or_default().to_owned();
+                table.push(vec![day.to_string(), String::new(), String::new(), error]);
+                continue;
+            }
+        };
+
+        if let Some(day_budget) = budget.day(day)
+            && report.total_time() > day_budget
+        {
+            over_budget.push(format!(
+                "day {day} took {}, over its budget of {}",
+                format_duration(report.total_time()),
+                format_duration(day_budget)
+            ));
+        }
+
+        total_parse += report.parse_time;
+        let mut day_parse = Some(report.parse_time);
+
+        let mut rows = unsolved
+            .remove(&day)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|part| {
+                let row = vec![
+                    day.to_string(),
+                    part.to_string(),
+                    String::new(),
+                    "unsolved".to_owned(),
+                ];
+                (part, row)
+            })
+            .collect::<Vec<_>>();
+
+        for part in report.parts {
+            let verdict = match answers.answer(set, part.part) {
+                Some(expected) => {
+                    let verdict = part.answer.verify(&expected);
+                    if !verdict.is_correct() {
+                        failures += 1;
+                    }
+
+                    verdict.to_string()
+                }
+                None => "no answer".to_owned(),
+            };
+
+            // The day is parsed once for every part, shown with the first.
+            let parse_time = part.parse_time.or(day_parse.take());
+            total_parse += part.parse_time.unwrap_or_default();
+            total_solve += part.solve_time;
+
+            let row = vec![
+                day.to_string(),
+                part.part.to_string(),
+                summarize(&part.answer),
+                verdict,
+                parse_time.map(format_duration).unwrap_or_default(),
+                format_duration(part.solve_time),
+                share(parse_time.unwrap_or_default() + part.solve_time),
+            ];
+            rows.push((part.part, row));
+        }
+
+        rows.sort_by_key(|(part, _)| *part);
+        for (_, row) in rows {
+            table.push(row);
+        }
+    }
+
+    table.push_footer(vec![
+        "Total".to_owned(),
+        String::new(),
+        String::new(),
+        String::new(),
+        format_duration(total_parse),
+        format_duration(total_solve),
+        share(total_time),
+    ]);
+
+    print!("{table}");
+    println!(
+        "\nTotal time: {} ({} wall clock with {jobs} job{})",
+        format_duration(total_time),
+        format_duration(wall_time),
+        if jobs == 1 { "" } else { "s" },
+    );
+
+    if let Some(total_budget) = total_budget {
+        println!("Budget: {}", format_duration(total_budget));
+
+        if total_time > total_budget {
+            over_budget.push(format!(
+                "all days took {}, over the budget of {}",
+                format_duration(total_time),
+                format_duration(total_budget)
+            ));
+        }
+    }
+
+    for message in &over_budget {
+        eprintln!("over budget: {message}");
+    }
+
+    match (failures, over_budget.len()) {
+        (0, 0) => Ok(()),
+        (0, _) => Err("over budget".to_owned().into()),
+        (failures, _) => Err(format!("{failures} solver(s) failed").into()),
+    }
+}
+
+/// Solves the parts of `day` in `entries`. Without any, e.g. when every part
+/// only has a placeholder solver, there's nothing to read or solve.
+fn run_one(day: usize, entries: &[&SolverEntry], set: &str) -> Outcome {
+    if entries.is_empty() {
+        let report = DayReport {
+            parse_time: Duration::ZERO,
+            parts: Vec::new(),
+        };
+
+        return Outcome::Solved(report, Answers::default());
+    }
+
+    let inputs = InputDir::for_day(day);
+
+    let input = match inputs.read(set) {
+        Ok(input) => input,
+        Err(InputError::NotFound { .. }) => return Outcome::MissingInput,
+        Err(error) => return Outcome::Failed(error.to_string()),
+    };
+
+    let answers = match Answers::load_or_default(&inputs.crate_dir().join("res/answers.toml")) {
+        Ok(answers) => answers,
+        Err(error) => return Outcome::Failed(error.to_string()),
+    };
+
+    match solvers::run_day(entries, &input) {
+        Ok(report) => Outcome::Solved(report, answers),
+        Err(error) => Outcome::Failed(format!("parse error at {error}")),
+    }
+}
+
+/// Shortens an answer to fit on a single line of the table.
+fn summarize(answer: &Answer) -> String {
+    match answer.clone().recognize() {
+        Ok(text) => text.to_string(),
+        Err(_) => format!("({} lines)", answer.to_string().lines().count()),
+    }
+}
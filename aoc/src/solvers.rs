@@ -0,0 +1,132 @@
+use std::{
+    sync::Mutex,
+    thread,
+    time::{Duration, Instant},
+};
+
+use common::{Answer, ParseError, registry::SolverEntry};
+
+// The day crates are only referenced through the solver registry, so make sure
+// they are linked into the binary.
+use day_1 as _;
+use day_2 as _;
+use day_3 as _;
+use day_4 as _;
+use day_5 as _;
+
+/// The answers produced by the solvers of a day along with how long it took
+/// to get there.
+pub struct DayReport {
+    pub parse_time: Duration,
+    pub parts: Vec<PartReport>,
+}
+
+impl DayReport {
+    /// Time spent parsing and solving every part.
+    pub fn total_time(&self) -> Duration {
+        self.parse_time
+            + self
+                .parts
+                .iter()
+                .map(|part| part.parse_time.unwrap_or_default() + part.solve_time)
+                .sum::<Duration>()
+    }
+}
+
+/// The answer produced by the solver of a single part.
+pub struct PartReport {
+    pub part: usize,
+    pub answer: Answer,
+
+    /// Only set if the solver couldn't use the input parsed for the day,
+    /// because it belongs to a different `AdventDay`.
+    pub parse_time: Option<Duration>,
+
+    /// Time spent solving, including creating the solver from the input
+    /// parsed for the day, which copies it for every part but the last.
+    pub solve_time: Duration,
+}
+
+/// Parses `input` once and solves it with every solver in `entries`, which
+/// should all be parts of the same day. Solvers own their data, so each part
+/// gets a copy of it except the last, which takes the parsed data itself.
+pub fn run_day(entries: &[&SolverEntry], input: &str) -> Result<DayReport, ParseError> {
+    let Some(first) = entries.first() else {
+        return Ok(DayReport {
+            parse_time: Duration::ZERO,
+            parts: Vec::new(),
+        });
+    };
+
+    let start = Instant::now();
+    let mut data = Some(first.parse_data(input)?);
+    let parse_time = start.elapsed();
+
+    let mut parts = Vec::new();
+
+    for (index, entry) in entries.iter().enumerate() {
+        let mut start = Instant::now();
+
+        let shared = if index + 1 == entries.len() {
+            data.take().and_then(|data| entry.into_solver(data).ok())
+        } else {
+            data.as_deref().and_then(|data| entry.with_data(data))
+        };
+
+        let (mut solver, parse_time) = match shared {
+            Some(solver) => (solver, None),
+            None => {
+                let parse_start = Instant::now();
+                let solver = entry.parse(input)?;
+                let parse_time = parse_start.elapsed();
+
+                start = Instant::now();
+                (solver, Some(parse_time))
+            }
+        };
+
+        let answer = solver.solve().answer();
+        let solve_time = start.elapsed();
+
+        parts.push(PartReport {
+            part: entry.part,
+            answer,
+            parse_time,
+            solve_time,
+        });
+    }
+
+    Ok(DayReport { parse_time, parts })
+}
+
+/// Calls `run` for every item on up to `jobs` threads at once, returning the
+/// results in the order of `items`.
+pub fn run_parallel<T: Send, R: Send>(
+    items: Vec<T>,
+    jobs: usize,
+    run: impl Fn(T) -> R + Sync,
+) -> Vec<R> {
+    let queue = Mutex::new(items.into_iter().enumerate());
+    let results = Mutex::new(Vec::new());
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.max(1) {
+            scope.spawn(|| {
+                loop {
+                    // Not a `while let`, which would hold the lock while running.
+                    let next = queue.lock().unwrap().next();
+                    let Some((index, item)) = next else {
+                        break;
+                    };
+
+                    let result = run(item);
+                    results.lock().unwrap().push((index, result));
+                }
+            });
+        }
+    });
+
+    let mut results = results.into_inner().unwrap();
+    results.sort_by_key(|(index, _)| *index);
+    results.into_iter().map(|(_, result)| result).collect()
+}
@@ -0,0 +1,224 @@
+use std::time::Duration;
+
+use common::{
+    answers::Answers,
+    calendar::{self, Calendar, Clock, format_countdown},
+    input::InputDir,
+};
+
+use crate::{
+    cli::{self, CliError, load_config, unexpected},
+    table::{Align, Table},
+};
+
+/// Progress on a single day.
+#[derive(Debug, PartialEq, Eq)]
+struct DayStatus {
+    day: usize,
+
+    /// Time left until the puzzle unlocks, `None` once it's unlocked.
+    locked: Option<Duration>,
+
+    /// Whether the day crate exists.
+    scaffolded: bool,
+
+    /// Whether the input set `input` exists.
+    input: bool,
+
+    /// Parts with an example input.
+    examples: Vec<usize>,
+
+    /// Whether each part has its answer for `input` in the manifest, or why
+    /// the manifest couldn't be loaded.
+    solved: Result<[bool; 2], String>,
+}
+
+impl DayStatus {
+    fn of(day: usize, calendar: &Calendar<impl Clock>, inputs: &InputDir) -> Self {
+        Self {
+            day,
+            locked: calendar.until_unlock(day),
+            scaffolded: inputs.crate_dir().join("Cargo.toml").is_file(),
+            input: inputs.resolve("input").is_ok(),
+            examples: [1, 2]
+                .into_iter()
+                .filter(|part| inputs.resolve(&format!("example_input_p{part}")).is_ok())
+                .collect(),
+            solved: Answers::load_or_default(&inputs.crate_dir().join("res/answers.toml"))
+                .map(|answers| [1, 2].map(|part| answers.get("input", part).is_some()))
+                .map_err(|error| error.to_string()),
+        }
+    }
+
+    fn row(&self) -> Vec<String> {
+        let mut row = vec![self.day.to_string()];
+
+        if let Some(remaining) = self.locked {
+            row.push(format!("in {}", format_countdown(remaining)));
+            return row;
+        }
+
+        let yes_or_missing = |present: bool| if present { "yes" } else { "missing" }.to_owned();
+
+        row.push("unlocked".to_owned());
+        row.push(yes_or_missing(self.scaffolded));
+        row.push(yes_or_missing(self.input));
+        row.push(match self.examples.as_slice() {
+            [] => "missing".to_owned(),
+            parts => parts
+                .iter()
+                .map(|part| format!("part {part}"))
+                .collect::<Vec<_>>()
+                .join(", "),
+        });
+
+        match self.solved {
+            Ok(solved) => {
+                for solved in solved {
+                    row.push(if solved { "solved" } else { "no answer" }.to_owned());
+                }
+            }
+            Err(_) => row.push("invalid res/answers.toml".to_owned()),
+        }
+
+        row
+    }
+}
+
+pub fn main(args: &[String]) -> Result<(), CliError> {
+    if let Some(arg) = args.first() {
+        return Err(unexpected(arg));
+    }
+
+    let calendar = cli::calendar(&load_config()?);
+    let statuses = statuses(&calendar, InputDir::for_day);
+
+    let mut table = Table::new(&[
+        ("Day", Align::Right),
+        ("Unlocks", Align::Left),
+        ("Crate", Align::Left),
+        ("Input", Align::Left),
+        ("Examples", Align::Left),
+        ("Part 1", Align::Left),
+        ("Part 2", Align::Left),
+    ]);
+
+    for status in &statuses {
+        table.push(status.row());
+    }
+
+    print!("{table}");
+
+    let unlocked = statuses.iter().filter(|status| status.locked.is_none());
+    let stars = unlocked
+        .clone()
+        .flat_map(|status| status.solved.clone().unwrap_or_default())
+        .filter(|&solved| solved)
+        .count();
+    println!(
+        "\n{stars} of {} stars in {}",
+        unlocked.count() * 2,
+        calendar.year()
+    );
+
+    let mut failures = 0;
+    for status in &statuses {
+        if let Err(error) = &status.solved {
+            eprintln!("day {}: {error}", status.day);
+            failures += 1;
+        }
+    }
+
+    match failures {
+        0 => Ok(()),
+        failures => Err(format!("{failures} day(s) have an invalid answers manifest").into()),
+    }
+}
+
+/// Progress on every unlocked day and the next one to unlock.
+fn statuses<C: Clock>(
+    calendar: &Calendar<C>,
+    inputs: impl Fn(usize) -> InputDir,
+) -> Vec<DayStatus> {
+    let last = calendar.next_unlock().unwrap_or(*calendar::DAYS.end());
+
+    (*calendar::DAYS.start()..=last)
+        .map(|day| DayStatus::of(day, calendar, &inputs(day)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::UNIX_EPOCH;
+
+    use common::{calendar::FixedClock, testing::TempDir};
+
+    use super::*;
+
+    #[test]
+    fn test_statuses() {
+        let dir = TempDir::new("status");
+        let day_1 = dir.join("day-1");
+        std::fs::create_dir_all(day_1.join("res/inputs")).unwrap();
+        std::fs::write(day_1.join("Cargo.toml"), "").unwrap();
+        std::fs::write(day_1.join("res/inputs/input"), "1 2\n").unwrap();
+        std::fs::write(day_1.join("res/inputs/example_input_p2"), "1 2\n").unwrap();
+        std::fs::write(day_1.join("res/answers.toml"), "[input]\npart1 = 3\n").unwrap();
+        std::fs::create_dir_all(dir.join("day-2/res")).unwrap();
+        std::fs::write(dir.join("day-2/res/answers.toml"), "[input]\npart1 = [\n").unwrap();
+
+        // An hour after day 2 unlocked in 2024.
+        let now = UNIX_EPOCH + Duration::from_secs(1_733_029_200 + 25 * 60 * 60);
+        let calendar = Calendar::with_clock(2024, FixedClock(now));
+        let statuses = statuses(&calendar, |day| {
+            InputDir::new(dir.join(format!("day-{day}")))
+        });
+
+        assert!(statuses[1].solved.is_err());
+        assert_eq!(
+            statuses[1].row(),
+            [
+                "2",
+                "unlocked",
+                "missing",
+                "missing",
+                "missing",
+                "invalid res/answers.toml"
+            ]
+        );
+        assert_eq!(
+            [&statuses[0], &statuses[2]],
+            [
+                &DayStatus {
+                    day: 1,
+                    locked: None,
+                    scaffolded: true,
+                    input: true,
+                    examples: vec![2],
+                    solved: Ok([true, false]),
+                },
+                &DayStatus {
+                    day: 3,
+                    locked: Some(Duration::from_secs(23 * 60 * 60)),
+                    scaffolded: false,
+                    input: false,
+                    examples: Vec::new(),
+                    solved: Ok([false, false]),
+                },
+            ]
+        );
+        assert_eq!(statuses[2].row(), ["3", "in 23h 0m 0s"]);
+        assert_eq!(
+            statuses[0].row(),
+            [
+                "1",
+                "unlocked",
+                "yes",
+                "yes",
+                "part 2",
+                "solved",
+                "no answer"
+            ]
+        );
+    }
+}
@@ -0,0 +1,190 @@
+use common::{
+    Answer,
+    bench::format_duration,
+    fetch::Client,
+    input::InputDir,
+    registry,
+    submit::{self, Guess, GuessLog, Outcome},
+};
+use toml_edit::{DocumentMut, Key};
+
+use crate::{
+    cli::{self, ArgParser, CliError, load_config, parse_number, require_unlocked, unexpected},
+    new::edit,
+    solvers,
+};
+
+pub fn main(args: &[String]) -> Result<(), CliError> {
+    let mut day = None;
+    let mut part = None;
+
+    let mut args = ArgParser::new(args);
+    while let Some(arg) = args.next() {
+        match arg {
+            value if day.is_none() && !value.starts_with('-') => {
+                day = Some(parse_number(value, "day")?);
+            }
+            value if part.is_none() && !value.starts_with('-') => {
+                part = Some(parse_number(value, "part")?);
+            }
+            _ => return Err(unexpected(arg)),
+        }
+    }
+
+    let day = day.ok_or_else(|| CliError::Usage("missing <day> argument".to_owned()))?;
+    let part = part.ok_or_else(|| CliError::Usage("missing <part> argument".to_owned()))?;
+    if !(1..=2).contains(&part) {
+        return Err(CliError::Usage(format!(
+            "invalid part `{part}`, expected 1 or 2"
+        )));
+    }
+
+    let config = load_config()?;
+    require_unlocked(&cli::calendar(&config), day)?;
+
+    let entry = registry::find(day, part)
+        .ok_or_else(|| format!("day {day} part {part} is not implemented"))?;
+    if entry.placeholder {
+        return Err(format!(
+            "day {day} part {part} isn't solved yet, its solver only returns a placeholder"
+        )
+        .into());
+    }
+
+    let inputs = InputDir::for_day(day);
+    let input = inputs.read("input").map_err(|error| error.to_string())?;
+
+    let report = solvers::run_day(&[entry], &input).map_err(|error| {
+        format!(
+            "failed to parse the input: {error}\n{}",
+            error.snippet(&input)
+        )
+    })?;
+    let answer = report.parts[0]
+        .answer
+        .clone()
+        .recognize()
+        .map_err(|error| format!("can't read the answer as text: {error}"))?;
+
+    println!("Day {day} part {part}: {answer}");
+
+    if answer.to_string().trim().is_empty() {
+        return Err("not submitting an empty answer".to_owned().into());
+    }
+
+    let log = GuessLog::for_crate(inputs.crate_dir());
+    submit::check(
+        &log.load().map_err(|error| error.to_string())?,
+        part,
+        &answer,
+    )
+    .map_err(|refusal| format!("not submitting `{answer}`: {refusal}"))?;
+
+    let outcome = Client::from_config(&config.client)
+        .submit(day, part, &answer)
+        .map_err(|error| error.to_string())?;
+
+    if outcome.is_checked() {
+        log.append(&Guess {
+            part,
+            answer: answer.to_string(),
+            outcome,
+        })
+        .map_err(|error| error.to_string())?;
+    }
+
+    match outcome {
+        Outcome::Correct => {
+            let path = inputs.crate_dir().join("res/answers.toml");
+            edit(&path, |manifest| {
+                record_answer(manifest, "input", part, &answer)
+            })?;
+
+            println!("Correct, recorded in {}", path.display());
+            Ok(())
+        }
+        Outcome::RateLimited(wait) => Err(format!(
+            "answered too recently, try again in {}",
+            format_duration(wait)
+        )
+        .into()),
+        Outcome::AlreadySolved => Err(format!("day {day} part {part} is already solved").into()),
+        outcome => Err(format!("`{answer}` is {outcome}").into()),
+    }
+}
+
+/// Sets the expected answer for `part` of the input set `set` in `manifest`,
+/// keeping everything else as is.
+pub fn record_answer(
+    manifest: &str,
+    set: &str,
+    part: usize,
+    answer: &Answer,
+) -> Result<String, String> {
+    let parse = |manifest: &str| {
+        manifest
+            .parse::<DocumentMut>()
+            .map_err(|error| error.to_string())
+    };
+
+    let mut document = parse(manifest)?;
+    if !document.contains_key(set) {
+        // Added as text, since a new table would end up above the comments
+        // at the end of the manifest.
+        let mut manifest = manifest.trim_end().to_owned();
+        if !manifest.is_empty() {
+            manifest.push_str("\n\n");
+        }
+        manifest.push_str(&format!("[{}]\n", Key::new(set)));
+
+        document = parse(&manifest)?;
+    }
+
+    let answers = document[set]
+        .as_table_mut()
+        .ok_or_else(|| format!("`{set}` should be a table of answers"))?;
+
+    // Integers TOML can't hold are written as strings, as is text.
+    let value = match answer {
+        Answer::Unsigned(answer) => i64::try_from(*answer)
+            .map_or_else(|_| toml_edit::value(answer.to_string()), toml_edit::value),
+        Answer::Signed(answer) => i64::try_from(*answer)
+            .map_or_else(|_| toml_edit::value(answer.to_string()), toml_edit::value),
+        answer => toml_edit::value(answer.to_string()),
+    };
+    answers.insert(&format!("part{part}"), value);
+
+    Ok(document.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_answer() {
+        let manifest = "# Expected answers.\n\n[input]\npart1 = 765748\n";
+
+        assert_eq!(
+            record_answer(manifest, "input", 2, &Answer::from(27_732_508)).unwrap(),
+            "# Expected answers.\n\n[input]\npart1 = 765748\npart2 = 27732508\n"
+        );
+        assert_eq!(
+            record_answer(manifest, "input", 1, &Answer::from("ABC")).unwrap(),
+            "# Expected answers.\n\n[input]\npart1 = \"ABC\"\n"
+        );
+        assert_eq!(
+            record_answer(
+                "# Expected answers.\n",
+                "input",
+                1,
+                &Answer::from(u128::MAX)
+            )
+            .unwrap(),
+            format!(
+                "# Expected answers.\n\n[input]\npart1 = \"{}\"\n",
+                u128::MAX
+            )
+        );
+    }
+}
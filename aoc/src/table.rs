@@ -0,0 +1,93 @@
+use std::fmt::Display;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Align {
+    Left,
+    Right,
+}
+
+/// A plain text table with a header, rows and optional footer rows below a
+/// separator, e.g. for totals.
+pub struct Table {
+    columns: Vec<(&'static str, Align)>,
+    rows: Vec<Vec<String>>,
+    footer: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new(columns: &[(&'static str, Align)]) -> Self {
+        Self {
+            columns: columns.to_vec(),
+            rows: Vec::new(),
+            footer: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, row: Vec<String>) {
+        self.rows.push(row);
+    }
+
+    pub fn push_footer(&mut self, row: Vec<String>) {
+        self.footer.push(row);
+    }
+
+    fn widths(&self) -> Vec<usize> {
+        self.columns
+            .iter()
+            .enumerate()
+            .map(|(index, (name, _))| {
+                self.rows
+                    .iter()
+                    .chain(&self.footer)
+                    .filter_map(|row| row.get(index))
+                    .map(|cell| cell.chars().count())
+                    .chain([name.len()])
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect()
+    }
+
+    fn write_row(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        widths: &[usize],
+        cells: impl Iterator<Item = impl Display>,
+    ) -> std::fmt::Result {
+        let line = cells
+            .zip(&self.columns)
+            .zip(widths)
+            .map(|((cell, (_, align)), &width)| match align {
+                Align::Left => format!("{cell:<width$}"),
+                Align::Right => format!("{cell:>width$}"),
+            })
+            .collect::<Vec<_>>()
+            .join("  ");
+
+        writeln!(f, "{}", line.trim_end())
+    }
+}
+
+impl Display for Table {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let widths = self.widths();
+        let separator = "-".repeat(widths.iter().sum::<usize>() + 2 * (widths.len() - 1));
+
+        self.write_row(f, &widths, self.columns.iter().map(|(name, _)| name))?;
+        writeln!(f, "{separator}")?;
+
+        for row in &self.rows {
+            self.write_row(f, &widths, row.iter())?;
+        }
+
+        if !self.footer.is_empty() {
+            writeln!(f, "{separator}")?;
+
+            for row in &self.footer {
+                self.write_row(f, &widths, row.iter())?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,106 @@
+#![allow(clippy::cargo_common_metadata)]
+
+//! Helpers for the build scripts of day crates. This crate has no
+//! dependencies so build scripts stay cheap to compile, `common` builds on
+//! it for locating inputs.
+
+use std::{
+    collections::BTreeSet,
+    path::{Path, PathBuf},
+};
+
+/// Environment variable pointing to a directory with a subdirectory per day.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Every directory searched for input sets of the day crate at `crate_dir`,
+/// in order of precedence: `$AOC_INPUT_DIR/<crate dir name>`, then
+/// `res/inputs` of the crate.
+#[must_use]
+pub fn input_dirs(crate_dir: &Path) -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+
+    if let Some(input_dir) = std::env::var_os(INPUT_DIR_VAR)
+        && let Some(day_dir) = crate_dir.file_name()
+    {
+        dirs.push(PathBuf::from(input_dir).join(day_dir));
+    }
+
+    dirs.push(crate_dir.join("res/inputs"));
+    dirs
+}
+
+/// Detects which input sets of a day crate are missing so its tests can be
+/// skipped instead of failing. Meant to be called from the build script of a
+/// day crate, where it sets `missing_input = "<name>"` for every missing set:
+///
+/// ```ignore
+/// #[test]
+/// #[cfg_attr(missing_input = "input", ignore = "missing input set `input`")]
+/// fn test_day_1_part_1() { ... }
+/// ```
+///
+/// # Panics
+///
+/// Will panic if not run from a build script.
+pub fn detect_missing_inputs() {
+    let crate_dir =
+        PathBuf::from(std::env::var("CARGO_MANIFEST_DIR").expect("not run from a build script"));
+    let dirs = input_dirs(&crate_dir);
+    let answers = crate_dir.join("res/answers.toml");
+
+    println!("cargo::rerun-if-env-changed={INPUT_DIR_VAR}");
+    println!("cargo::rustc-check-cfg=cfg(missing_input, values(any()))");
+
+    for dir in &dirs {
+        println!("cargo::rerun-if-changed={}", dir.display());
+    }
+    println!("cargo::rerun-if-changed={}", answers.display());
+
+    let mut sets = ["input", "example_input_p1", "example_input_p2"]
+        .into_iter()
+        .map(str::to_owned)
+        .collect::<BTreeSet<_>>();
+    sets.extend(answer_sets(
+        &std::fs::read_to_string(answers).unwrap_or_default(),
+    ));
+
+    for set in sets {
+        if !dirs.iter().any(|dir| dir.join(&set).is_file()) {
+            println!("cargo::rustc-cfg=missing_input=\"{set}\"");
+        }
+    }
+}
+
+/// Names of the tables of an answers manifest, which are named after input
+/// sets. Only table headers on their own line are recognized, which is how
+/// the manifests are written. Anything else that looks like one at worst
+/// marks a set nothing tests as missing.
+fn answer_sets(manifest: &str) -> Vec<String> {
+    manifest
+        .lines()
+        .filter_map(|line| {
+            let line = line.trim();
+            let header = line.strip_prefix('[')?;
+            let end = header.find(']')?;
+
+            if header.starts_with('[') {
+                return None;
+            }
+
+            Some(header[..end].trim().trim_matches('"').to_owned())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answer_sets() {
+        let manifest = "# Answers\n[input]\npart1 = 3\n\n[ \"alt input\" ] # second account\n\
+                        part2 = \"\"\"\n#..#\n\"\"\"\n[[not_a_set]]\n";
+
+        assert_eq!(answer_sets(manifest), ["input", "alt input"]);
+    }
+}
@@ -0,0 +1,95 @@
+#![allow(clippy::cargo_common_metadata)]
+
+//! Procedural macros re-exported by `common`, use them through there.
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{ItemImpl, LitInt, parse_macro_input, spanned::Spanned};
+
+/// Registers the solver of an `AdventSolver` impl for the given day and part,
+/// see `common::register_solver!`.
+///
+/// ```ignore
+/// #[common::advent_solver(day = 1, part = 1)]
+/// impl AdventSolver<usize, (Vec<usize>, Vec<usize>)> for Day1Part1Solver {
+///     ...
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn advent_solver(args: TokenStream, item: TokenStream) -> TokenStream {
+    let mut day = None;
+    let mut part = None;
+
+    let parser = syn::meta::parser(|meta| {
+        let target = if meta.path.is_ident("day") {
+            &mut day
+        } else if meta.path.is_ident("part") {
+            &mut part
+        } else {
+            return Err(meta.error("expected `day` or `part`"));
+        };
+
+        if target.is_some() {
+            return Err(meta.error("duplicate argument"));
+        }
+
+        *target = Some(meta.value()?.parse::<LitInt>()?);
+        Ok(())
+    });
+
+    parse_macro_input!(args with parser);
+    let item = parse_macro_input!(item as ItemImpl);
+
+    match expand(day, part, &item) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => {
+            let error = error.to_compile_error();
+            quote!(#item #error).into()
+        }
+    }
+}
+
+fn expand(
+    day: Option<LitInt>,
+    part: Option<LitInt>,
+    item: &ItemImpl,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let missing = |name| {
+        syn::Error::new(
+            proc_macro2::Span::call_site(),
+            format!("missing `{name} = <number>` argument"),
+        )
+    };
+
+    let day = day.ok_or_else(|| missing("day"))?;
+    let part = part.ok_or_else(|| missing("part"))?;
+
+    if !matches!(part.base10_parse::<usize>()?, 1 | 2) {
+        return Err(syn::Error::new(part.span(), "expected part 1 or 2"));
+    }
+
+    let Some((_, trait_path, _)) = &item.trait_ else {
+        return Err(syn::Error::new(
+            item.self_ty.span(),
+            "expected an `impl AdventSolver<..> for ..` block",
+        ));
+    };
+
+    if trait_path
+        .segments
+        .last()
+        .is_none_or(|segment| segment.ident != "AdventSolver")
+    {
+        return Err(syn::Error::new(
+            trait_path.span(),
+            "expected an implementation of `AdventSolver`",
+        ));
+    }
+
+    let solver = &item.self_ty;
+
+    Ok(quote! {
+        #item
+        ::common::register_solver!(#day, #part, #solver);
+    })
+}
@@ -0,0 +1,12 @@
+fn main() {
+    // Recorded in the benchmark history, see `bench::history::TOOLCHAIN`.
+    let rustc = std::env::var("RUSTC").unwrap_or_else(|_| "rustc".to_owned());
+    let version = std::process::Command::new(rustc)
+        .arg("--version")
+        .output()
+        .ok()
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .unwrap_or_default();
+
+    println!("cargo::rustc-env=AOC_RUSTC_VERSION={}", version.trim());
+}
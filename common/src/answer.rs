@@ -0,0 +1,269 @@
+use std::{cmp::Ordering, convert::Infallible, fmt::Display, str::FromStr};
+
+use crate::{
+    AdventSolution, Distance, Verdict,
+    ocr::{self, OcrError},
+    verdict,
+};
+
+/// An answer of any day, regardless of the type its solver produces, so
+/// answers can be compared, stored and submitted uniformly.
+///
+/// Equal answers always have the same variant: integers are `Unsigned` unless
+/// they're negative, and text is `Lines` only if it spans several lines.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Unsigned(u128),
+
+    /// A negative integer.
+    Signed(i128),
+
+    /// A single line of text.
+    Text(String),
+
+    /// Text spanning several lines, e.g. a picture drawn by the solution.
+    Lines(Vec<String>),
+}
+
+impl Answer {
+    /// Compares the answer against the `expected` one. Integers also report
+    /// whether they're too high or too low. Answers drawn in block letters
+    /// match the text they read as.
+    #[must_use]
+    pub fn verify(&self, expected: &Answer) -> Verdict {
+        let found = self.clone().recognize().unwrap_or_else(|_| self.clone());
+        let expected_text = expected
+            .clone()
+            .recognize()
+            .unwrap_or_else(|_| expected.clone());
+
+        match verdict::verdict(&found, &expected_text) {
+            Verdict::Mismatch { .. } => Verdict::Mismatch {
+                expected: format!("`{expected}`"),
+                found: format!("`{self}`"),
+            },
+            verdict => verdict,
+        }
+    }
+
+    /// Reads an answer drawn in block letters as text, see [`ocr`](crate::ocr).
+    /// Answers that aren't multi-line are returned as is.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `OcrError` if a multi-line answer isn't made of known letters.
+    pub fn recognize(self) -> Result<Answer, OcrError> {
+        match self {
+            Answer::Lines(lines) => ocr::decode(&lines.join("\n")).map(Answer::Text),
+            answer => Ok(answer),
+        }
+    }
+
+    /// Whether the answer is an integer.
+    #[must_use]
+    pub fn is_integer(&self) -> bool {
+        matches!(self, Answer::Unsigned(_) | Answer::Signed(_))
+    }
+}
+
+/// Formats the answer the way it's submitted, e.g. `-12` or `HELLO`. The lines
+/// of multi-line text are separated by `\n`.
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Unsigned(answer) => write!(f, "{answer}"),
+            Answer::Signed(answer) => write!(f, "{answer}"),
+            Answer::Text(answer) => write!(f, "{answer}"),
+            Answer::Lines(lines) => write!(f, "{}", lines.join("\n")),
+        }
+    }
+}
+
+/// Parses an answer as written in the answers manifest. Anything that isn't
+/// an integer is text, so parsing never fails.
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let trimmed = text.trim();
+
+        if let Ok(answer) = trimmed.parse::<u128>() {
+            return Ok(Answer::Unsigned(answer));
+        }
+
+        if let Ok(answer) = trimmed.parse::<i128>() {
+            return Ok(answer.into());
+        }
+
+        Ok(text.into())
+    }
+}
+
+impl Distance for Answer {
+    fn distance(&self, other: &Self) -> Option<(Ordering, u128)> {
+        match (self, other) {
+            (Answer::Unsigned(answer), Answer::Unsigned(other)) => answer.distance(other),
+            (Answer::Signed(answer), Answer::Signed(other)) => answer.distance(other),
+            (Answer::Signed(answer), Answer::Unsigned(other)) => {
+                Some((Ordering::Less, answer.unsigned_abs().saturating_add(*other)))
+            }
+            (Answer::Unsigned(answer), Answer::Signed(other)) => Some((
+                Ordering::Greater,
+                answer.saturating_add(other.unsigned_abs()),
+            )),
+            _ => None,
+        }
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($number:ty),*) => {
+        $(
+            impl From<$number> for Answer {
+                #[allow(clippy::cast_lossless, clippy::unnecessary_cast)]
+                fn from(answer: $number) -> Self {
+                    Answer::from(answer as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(i8, i16, i32, i64, isize);
+
+impl From<i128> for Answer {
+    fn from(answer: i128) -> Self {
+        match u128::try_from(answer) {
+            Ok(answer) => Answer::Unsigned(answer),
+            Err(_) => Answer::Signed(answer),
+        }
+    }
+}
+
+macro_rules! impl_from_unsigned {
+    ($($number:ty),*) => {
+        $(
+            impl From<$number> for Answer {
+                #[allow(clippy::cast_lossless, clippy::unnecessary_cast)]
+                fn from(answer: $number) -> Self {
+                    Answer::Unsigned(answer as u128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_unsigned!(u8, u16, u32, u64, u128, usize);
+
+impl From<&str> for Answer {
+    /// Text is trimmed of surrounding blank lines, and of surrounding
+    /// whitespace if it's a single line.
+    fn from(answer: &str) -> Self {
+        let answer = answer.trim_matches(['\n', '\r']);
+
+        if answer.contains('\n') {
+            Answer::Lines(answer.lines().map(str::to_owned).collect())
+        } else {
+            Answer::Text(answer.trim().to_owned())
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(answer: String) -> Self {
+        Answer::from(answer.as_str())
+    }
+}
+
+impl From<char> for Answer {
+    fn from(answer: char) -> Self {
+        Answer::from(answer.to_string())
+    }
+}
+
+impl<T> From<AdventSolution<T>> for Answer
+where
+    T: PartialEq + std::fmt::Debug + Into<Answer>,
+{
+    fn from(solution: AdventSolution<T>) -> Self {
+        solution.solution.into()
+    }
+}
+
+impl<T> From<&AdventSolution<T>> for Answer
+where
+    T: PartialEq + std::fmt::Debug + Clone + Into<Answer>,
+{
+    fn from(solution: &AdventSolution<T>) -> Self {
+        solution.solution.clone().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_integers_normalize() {
+        assert_eq!(Answer::from(5_i32), Answer::from(5_usize));
+        assert_eq!(Answer::from(-5_i64), Answer::Signed(-5));
+        assert_eq!(Answer::from(u128::MAX), Answer::Unsigned(u128::MAX));
+    }
+
+    #[test]
+    fn test_parse_answer() {
+        assert_eq!("765748".parse(), Ok(Answer::Unsigned(765_748)));
+        assert_eq!(" -12\n".parse(), Ok(Answer::Signed(-12)));
+        assert_eq!("1,2,3".parse(), Ok(Answer::Text("1,2,3".to_owned())));
+        assert_eq!(
+            "\n#..\n.#.\n".parse(),
+            Ok(Answer::Lines(vec!["#..".to_owned(), ".#.".to_owned()]))
+        );
+    }
+
+    #[test]
+    fn test_answer_display_round_trips() {
+        for answer in [
+            Answer::Unsigned(42),
+            Answer::Signed(-42),
+            Answer::Text("abc".to_owned()),
+            Answer::Lines(vec![".#".to_owned(), "#.".to_owned()]),
+        ] {
+            assert_eq!(answer.to_string().parse(), Ok(answer));
+        }
+    }
+
+    #[test]
+    fn test_answer_verify() {
+        assert!(Answer::from(3_u8).verify(&Answer::Unsigned(3)).is_correct());
+        assert_eq!(
+            Answer::Signed(-2).verify(&Answer::Unsigned(3)),
+            Verdict::TooLow { delta: 5 }
+        );
+        assert_eq!(
+            Answer::Unsigned(3).verify(&Answer::Text("abc".to_owned())),
+            Verdict::Mismatch {
+                expected: "`abc`".to_owned(),
+                found: "`3`".to_owned(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_answer_verify_block_letters() {
+        let drawn =
+            Answer::from(".##..###.\n#..#.#..#\n#..#.###.\n####.#..#\n#..#.#..#\n#..#.###.");
+
+        assert_eq!(drawn.clone().recognize(), Ok(Answer::Text("AB".to_owned())));
+        assert!(drawn.verify(&Answer::Text("AB".to_owned())).is_correct());
+        assert!(!drawn.verify(&Answer::Text("AC".to_owned())).is_correct());
+    }
+
+    #[test]
+    fn test_answer_from_solution() {
+        let solution = AdventSolution::from(String::from("HELLO"));
+
+        assert_eq!(Answer::from(&solution), Answer::Text("HELLO".to_owned()));
+        assert_eq!(Answer::from(solution).to_string(), "HELLO");
+    }
+}
@@ -0,0 +1,396 @@
+//! Expected answers for each day, stored in `res/answers.toml` next to the inputs.
+//!
+//! Every table is named after an input set in `res/inputs` and holds the
+//! expected answer for each part solved with that input:
+//!
+//! ```toml
+//! [input]
+//! part1 = 765748
+//! part2 = 27732508
+//!
+//! [example_input_p1]
+//! part1 = 11
+//! ```
+//!
+//! Answers that don't fit in a TOML integer can be written as strings, as
+//! can text answers. Multi-line answers use TOML's multi-line strings.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Display,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use crate::{
+    Answer,
+    input::{self, InputDir, InputError},
+    registry,
+};
+
+/// The expected answers of a single day, keyed by input set and part.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers {
+    sets: BTreeMap<String, BTreeMap<usize, String>>,
+}
+
+impl Answers {
+    /// Parses the contents of an answers manifest.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AnswersError` if `text` isn't valid TOML or doesn't have
+    /// the shape described in the module documentation.
+    pub fn parse(text: &str) -> Result<Self, AnswersError> {
+        let table = text
+            .parse::<toml::Table>()
+            .map_err(|error| AnswersError::Toml(error.to_string()))?;
+
+        let mut sets = BTreeMap::new();
+
+        for (set, parts) in table {
+            let toml::Value::Table(parts) = parts else {
+                return Err(AnswersError::Shape(format!(
+                    "`{set}` should be a table of answers"
+                )));
+            };
+
+            let mut answers = BTreeMap::new();
+
+            for (key, value) in parts {
+                let part = key
+                    .strip_prefix("part")
+                    .and_then(|part| part.parse::<usize>().ok())
+                    .ok_or_else(|| {
+                        AnswersError::Shape(format!("`{set}.{key}` should be named `part<N>`"))
+                    })?;
+
+                let answer = match value {
+                    toml::Value::Integer(answer) => answer.to_string(),
+                    toml::Value::String(answer) => answer,
+                    _ => {
+                        return Err(AnswersError::Shape(format!(
+                            "`{set}.{key}` should be an integer or a string"
+                        )));
+                    }
+                };
+
+                answers.insert(part, answer);
+            }
+
+            sets.insert(set, answers);
+        }
+
+        Ok(Self { sets })
+    }
+
+    /// Reads and parses the answers manifest at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AnswersError` if the file can't be read or parsed.
+    pub fn load(path: &Path) -> Result<Self, AnswersError> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|error| AnswersError::Io(path.to_owned(), error))?;
+
+        Self::parse(&text)
+    }
+
+    /// Like [`load`](Self::load), but a manifest that doesn't exist has no
+    /// answers, e.g. for a day that isn't solved yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AnswersError` if the file exists but can't be read or parsed.
+    pub fn load_or_default(path: &Path) -> Result<Self, AnswersError> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(AnswersError::Io(path.to_owned(), error)),
+        }
+    }
+
+    /// Returns the expected answer for `part` when solved with `input_set`.
+    #[must_use]
+    pub fn get(&self, input_set: &str, part: usize) -> Option<&str> {
+        self.sets.get(input_set)?.get(&part).map(String::as_str)
+    }
+
+    /// Like [`get`](Self::get), but parsed into an [`Answer`].
+    #[must_use]
+    pub fn answer(&self, input_set: &str, part: usize) -> Option<Answer> {
+        self.get(input_set, part).map(|answer| {
+            let Ok(answer) = answer.parse();
+            answer
+        })
+    }
+
+    /// Returns the name of every input set with at least one expected answer.
+    pub fn sets(&self) -> impl Iterator<Item = &str> {
+        self.sets.keys().map(String::as_str)
+    }
+}
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Io(PathBuf, std::io::Error),
+    Toml(String),
+    Shape(String),
+}
+
+impl Display for AnswersError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnswersError::Io(path, error) => {
+                write!(f, "failed to read {}: {error}", path.display())
+            }
+            AnswersError::Toml(error) => write!(f, "invalid answers manifest: {error}"),
+            AnswersError::Shape(error) => write!(f, "invalid answers manifest: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for AnswersError {}
+
+/// Reads the expected answer for `part` when solved with `input_set`
+/// from `res/answers.toml` of the day crate at `crate_dir`.
+/// Use [`read_answer!`](crate::read_answer) from inside a day crate.
+///
+/// # Panics
+///
+/// Will panic if the manifest can't be loaded, has no answer for `part` of
+/// `input_set`, or the answer can't be parsed into a `T`.
+#[must_use]
+pub fn read_answer<T: FromStr>(crate_dir: &str, input_set: &str, part: usize) -> T {
+    let answers = Answers::load(&Path::new(crate_dir).join("res/answers.toml"))
+        .unwrap_or_else(|error| panic!("{error}"));
+
+    let answer = answers
+        .get(input_set, part)
+        .unwrap_or_else(|| panic!("no answer for part {part} of `{input_set}`"));
+
+    answer
+        .parse()
+        .unwrap_or_else(|_| panic!("answer `{answer}` has the wrong type"))
+}
+
+/// Reads the expected answer for a part and input set of the calling day crate.
+///
+/// ```ignore
+/// solution.prove(&common::read_answer!("input", 1));
+/// ```
+#[macro_export]
+macro_rules! read_answer {
+    ($input_set:expr, $part:expr) => {
+        $crate::answers::read_answer(env!("CARGO_MANIFEST_DIR"), $input_set, $part)
+    };
+}
+
+/// Proves every registered solver for `day` against every input set of the
+/// day crate at `crate_dir`, using the expected answers in its `res/answers.toml`.
+///
+/// Input sets are taken both from the input directories and the manifest, so
+/// an input without answers fails. Answers whose input set is missing from the
+/// checkout are skipped, since inputs are personal and mostly not committed.
+/// Example sets (`example_input_p<N>`) are only solved by part N.
+///
+/// Meant to be called from a test in each day crate:
+///
+/// ```ignore
+/// common::answers::prove_registered(1, env!("CARGO_MANIFEST_DIR"));
+/// ```
+///
+/// # Panics
+///
+/// Will panic listing every solver and input set that failed, or if the
+/// answers manifest can't be loaded.
+pub fn prove_registered(day: usize, crate_dir: &str) {
+    let inputs = InputDir::new(crate_dir);
+
+    let answers = Answers::load(&inputs.crate_dir().join("res/answers.toml"))
+        .unwrap_or_else(|error| panic!("{error}"));
+
+    let mut sets = inputs.sets().into_iter().collect::<BTreeSet<_>>();
+    sets.extend(answers.sets().map(str::to_owned));
+
+    let mut failures = Vec::new();
+
+    for entry in registry::solvers()
+        .into_iter()
+        .filter(|entry| entry.day == day)
+    {
+        for set in &sets {
+            if input::example_part(set).is_some_and(|part| part != entry.part) {
+                continue;
+            }
+
+            let Some(expected) = answers.get(set, entry.part) else {
+                failures.push(format!(
+                    "{} on `{set}`: no expected answer for part {}",
+                    entry.name, entry.part
+                ));
+                continue;
+            };
+
+            let input = match inputs.read(set) {
+                Ok(input) => input,
+                Err(InputError::NotFound { .. }) => {
+                    eprintln!("skipped {} on `{set}`: input set is missing", entry.name);
+                    continue;
+                }
+                Err(error) => {
+                    failures.push(format!("{} on `{set}`: {error}", entry.name));
+                    continue;
+                }
+            };
+
+            let mut solver = match entry.parse(&input) {
+                Ok(solver) => solver,
+                Err(error) => {
+                    failures.push(format!("{} on `{set}`: {error}", entry.name));
+                    continue;
+                }
+            };
+
+            let verdict = solver.solve().verify_str(expected);
+            if !verdict.is_correct() {
+                failures.push(format!("{} on `{set}`: {verdict}", entry.name));
+            }
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "Proof invalid for day {day}:\n{}",
+        failures.join("\n")
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        AdventDay, AdventSolution, AdventSolver, ParseError, parse_token, testing::TempDir,
+    };
+
+    use super::*;
+
+    struct Day0;
+
+    impl AdventDay for Day0 {
+        type Data = Vec<i64>;
+
+        fn parse(input: &str) -> Result<Self::Data, ParseError> {
+            input
+                .lines()
+                .map(|line| parse_token(input, line, "an integer"))
+                .collect()
+        }
+    }
+
+    struct SumSolver {
+        data: Vec<i64>,
+    }
+
+    // Day 0 doesn't exist, so it can't clash with a real solver.
+    #[crate::advent_solver(day = 0, part = 1)]
+    impl AdventSolver<i64, Vec<i64>> for SumSolver {
+        type Day = Day0;
+
+        fn from_data(data: Vec<i64>) -> Self {
+            Self { data }
+        }
+
+        fn solve(&mut self) -> AdventSolution<i64> {
+            self.data.iter().sum::<i64>().into()
+        }
+
+        fn data(&self) -> &Vec<i64> {
+            &self.data
+        }
+    }
+
+    #[test]
+    fn test_advent_solver_registers() {
+        let entry = crate::registry::find(0, 1).unwrap();
+
+        assert_eq!(entry.name, "SumSolver");
+        assert_eq!(entry.answer_type(), "i64");
+        assert!(!entry.in_place);
+        assert!(!entry.placeholder);
+    }
+
+    #[test]
+    fn test_prove_registered_every_input_set() {
+        let crate_dir = TempDir::new("day-0");
+        std::fs::create_dir_all(crate_dir.join("res/inputs")).unwrap();
+
+        std::fs::write(crate_dir.join("res/inputs/alice"), "1\n2\n").unwrap();
+        std::fs::write(crate_dir.join("res/inputs/bob"), "5\n").unwrap();
+        std::fs::write(crate_dir.join("res/inputs/example_input_p2"), "7\n").unwrap();
+        std::fs::write(
+            crate_dir.join("res/answers.toml"),
+            "[alice]\npart1 = 3\n\n[bob]\npart1 = 4\n\n[carol]\npart1 = 6\n",
+        )
+        .unwrap();
+
+        let result = std::panic::catch_unwind(|| {
+            prove_registered(0, crate_dir.path().to_str().unwrap());
+        });
+
+        let message = *result.unwrap_err().downcast::<String>().unwrap();
+        assert!(!message.contains("`alice`"));
+        assert!(message.contains("SumSolver on `bob`: too high by 1"));
+        assert!(!message.contains("`carol`"));
+        assert!(!message.contains("example_input_p2"));
+    }
+
+    #[test]
+    fn test_parse_answers() {
+        let answers = Answers::parse(
+            "[input]\npart1 = 765748\npart2 = \"340282366920938463463374607431768211455\"\n\n\
+             [example_input_p1]\npart1 = 11\n",
+        )
+        .unwrap();
+
+        assert_eq!(answers.get("input", 1), Some("765748"));
+        assert_eq!(
+            answers.get("input", 2),
+            Some("340282366920938463463374607431768211455")
+        );
+        assert_eq!(answers.get("example_input_p1", 1), Some("11"));
+        assert_eq!(answers.get("example_input_p1", 2), None);
+        assert_eq!(answers.answer("input", 1), Some(Answer::Unsigned(765748)));
+        assert_eq!(
+            answers.answer("input", 2),
+            Some(Answer::Unsigned(u128::MAX))
+        );
+        assert_eq!(
+            answers.sets().collect::<Vec<_>>(),
+            ["example_input_p1", "input"]
+        );
+    }
+
+    #[test]
+    fn test_load_or_default() {
+        assert_eq!(
+            Answers::load_or_default(Path::new("/nonexistent/res/answers.toml")).unwrap(),
+            Answers::default()
+        );
+
+        // Exists, but can't be read as a file.
+        let dir = TempDir::new("answers");
+        assert!(Answers::load_or_default(dir.path()).is_err());
+    }
+
+    #[test]
+    fn test_parse_answers_invalid_shape() {
+        assert!(matches!(
+            Answers::parse("[input]\nfirst = 1\n"),
+            Err(AnswersError::Shape(_))
+        ));
+        assert!(matches!(
+            Answers::parse("[input]\npart1 = 1.5\n"),
+            Err(AnswersError::Shape(_))
+        ));
+    }
+}
@@ -0,0 +1,373 @@
+//! Benchmarking harness that times parsing and solving separately.
+//!
+//! Unlike `#[bench]` this works on any toolchain, so it can be driven from the
+//! runner as well as from tests. Results can be kept in a [`history`] to
+//! detect regressions between commits.
+
+pub mod history;
+
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use serde::{Serialize, Serializer};
+
+use crate::{ParseError, registry::SolverEntry};
+
+/// How many times each phase is run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchConfig {
+    /// Untimed runs before measuring, to warm up caches and the allocator.
+    pub warmup: usize,
+
+    /// Timed runs, each contributing one sample to the statistics.
+    pub iterations: usize,
+
+    /// Solve a freshly parsed solver every iteration, even if it doesn't
+    /// declare [`IN_PLACE`](crate::AdventSolver::IN_PLACE).
+    pub always_fresh: bool,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            warmup: 10,
+            iterations: 100,
+            always_fresh: false,
+        }
+    }
+}
+
+/// Summary of the samples taken for a single phase. Serialized with
+/// durations in nanoseconds and without the samples.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Stats {
+    pub iterations: usize,
+    #[serde(rename = "min_ns", serialize_with = "serialize_nanos")]
+    pub min: Duration,
+    #[serde(rename = "median_ns", serialize_with = "serialize_nanos")]
+    pub median: Duration,
+    #[serde(rename = "p95_ns", serialize_with = "serialize_nanos")]
+    pub p95: Duration,
+    #[serde(rename = "max_ns", serialize_with = "serialize_nanos")]
+    pub max: Duration,
+    #[serde(rename = "mean_ns", serialize_with = "serialize_nanos")]
+    pub mean: Duration,
+
+    /// Every sample taken, in ascending order.
+    #[serde(skip)]
+    pub samples: Vec<Duration>,
+}
+
+impl Stats {
+    /// Computes the statistics of `samples`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `samples` is empty.
+    #[must_use]
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "no samples to compute statistics of");
+
+        samples.sort_unstable();
+
+        let count = samples.len();
+        let median = if count.is_multiple_of(2) {
+            (samples[count / 2 - 1] + samples[count / 2]) / 2
+        } else {
+            samples[count / 2]
+        };
+
+        // Nearest-rank percentile
+        let p95 = samples[(count * 95).div_ceil(100) - 1];
+
+        Self {
+            iterations: count,
+            min: samples[0],
+            median,
+            p95,
+            max: samples[count - 1],
+            mean: samples.iter().sum::<Duration>() / u32::try_from(count).unwrap_or(u32::MAX),
+            samples,
+        }
+    }
+}
+
+impl std::fmt::Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "min {:>9}  median {:>9}  p95 {:>9}",
+            format_duration(self.min),
+            format_duration(self.median),
+            format_duration(self.p95),
+        )
+    }
+}
+
+/// Benchmark results of a single solver.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BenchResult {
+    pub day: usize,
+    pub part: usize,
+    pub name: &'static str,
+
+    /// Whether every solve was timed on a freshly parsed solver.
+    pub fresh: bool,
+
+    pub parse: Stats,
+    pub solve: Stats,
+}
+
+impl BenchResult {
+    /// Formats the results as a single line of JSON with durations in nanoseconds.
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("bench results are valid JSON")
+    }
+}
+
+/// Duration in whole nanoseconds, saturating at `u64::MAX`.
+pub(crate) fn nanos(duration: Duration) -> u64 {
+    u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX)
+}
+
+fn serialize_nanos<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u64(nanos(*duration))
+}
+
+/// Runs `phase` `config.warmup` times untimed and then `config.iterations`
+/// times timed, passing the result of each run to `black_box`.
+///
+/// # Panics
+///
+/// Will panic if `config.iterations` is zero.
+pub fn measure<R>(config: &BenchConfig, mut phase: impl FnMut() -> R) -> Stats {
+    measure_with_setup(config, || (), |()| phase())
+}
+
+/// Like [`measure`], but `phase` works on a fresh state created by `setup`
+/// before every run. Neither `setup` nor dropping the state and the result
+/// of `phase` are timed.
+///
+/// # Panics
+///
+/// Will panic if `config.iterations` is zero.
+pub fn measure_with_setup<S, R>(
+    config: &BenchConfig,
+    mut setup: impl FnMut() -> S,
+    mut phase: impl FnMut(&mut S) -> R,
+) -> Stats {
+    for _ in 0..config.warmup {
+        let mut state = setup();
+        black_box(phase(&mut state));
+    }
+
+    let samples = (0..config.iterations)
+        .map(|_| {
+            let mut state = setup();
+
+            let start = Instant::now();
+            let result = black_box(phase(&mut state));
+            let elapsed = start.elapsed();
+
+            drop(result);
+            elapsed
+        })
+        .collect::<Vec<_>>();
+
+    Stats::from_samples(samples)
+}
+
+/// Benchmarks parsing `input` and solving it with the solver in `entry`.
+///
+/// Only the parser of the day is timed, not creating the solver from its
+/// output. Solvers that modify their parsed data are recreated from a
+/// pristine copy of it outside of the timed region before every solve, so
+/// each solve starts from the same state.
+///
+/// # Errors
+///
+/// Returns a `ParseError` if the solver failed to parse `input`.
+pub fn bench(
+    entry: &SolverEntry,
+    input: &str,
+    config: &BenchConfig,
+) -> Result<BenchResult, ParseError> {
+    // Bail out on invalid input before spending time measuring anything.
+    let data = entry.parse_data(input)?;
+    let pristine = || {
+        entry
+            .with_data(&*data)
+            .expect("data parsed by the day of the solver")
+    };
+
+    let parse = measure(config, || entry.parse_data(black_box(input)));
+
+    let fresh = entry.in_place || config.always_fresh;
+    let solve = if fresh {
+        measure_with_setup(config, pristine, |solver| solver.solve())
+    } else {
+        let mut solver = pristine();
+        measure(config, || solver.solve())
+    };
+
+    Ok(BenchResult {
+        day: entry.day,
+        part: entry.part,
+        name: entry.name,
+        fresh,
+        parse,
+        solve,
+    })
+}
+
+/// Formats `duration` with a unit suited to its magnitude, e.g. `1.50ms`.
+#[must_use]
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+
+    if nanos < 1_000 {
+        format!("{nanos}ns")
+    } else if nanos < 1_000_000 {
+        format!("{:.2}µs", duration.as_secs_f64() * 1e6)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.2}ms", duration.as_secs_f64() * 1e3)
+    } else {
+        format!("{:.2}s", duration.as_secs_f64())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AdventDay, AdventSolution, AdventSolver};
+
+    use super::*;
+
+    struct Unsorted;
+
+    impl AdventDay for Unsorted {
+        type Data = Vec<u8>;
+
+        fn parse(input: &str) -> Result<Self::Data, ParseError> {
+            Ok(input.bytes().collect())
+        }
+    }
+
+    struct SortingSolver {
+        data: Vec<u8>,
+    }
+
+    impl AdventSolver<usize, Vec<u8>> for SortingSolver {
+        type Day = Unsorted;
+
+        const IN_PLACE: bool = true;
+
+        fn from_data(data: Vec<u8>) -> Self {
+            Self { data }
+        }
+
+        fn solve(&mut self) -> AdventSolution<usize> {
+            // Would fail if the data was sorted by a previous solve.
+            assert!(!self.data.is_sorted());
+            self.data.sort_unstable();
+            self.data.len().into()
+        }
+
+        fn data(&self) -> &Vec<u8> {
+            &self.data
+        }
+    }
+
+    #[test]
+    fn test_stats_from_samples() {
+        let samples = (1..=20)
+            .rev()
+            .map(Duration::from_millis)
+            .collect::<Vec<_>>();
+        let stats = Stats::from_samples(samples);
+
+        assert_eq!(stats.iterations, 20);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_micros(10_500));
+        assert_eq!(stats.p95, Duration::from_millis(19));
+        assert_eq!(stats.max, Duration::from_millis(20));
+        assert_eq!(stats.mean, Duration::from_micros(10_500));
+    }
+
+    #[test]
+    fn test_measure_runs_every_iteration() {
+        let mut runs = 0;
+        let config = BenchConfig {
+            warmup: 3,
+            iterations: 7,
+            always_fresh: false,
+        };
+
+        let stats = measure(&config, || runs += 1);
+
+        assert_eq!(runs, 10);
+        assert_eq!(stats.iterations, 7);
+    }
+
+    #[test]
+    fn test_measure_with_setup_starts_fresh() {
+        let config = BenchConfig::default();
+        let mut setups = 0;
+
+        measure_with_setup(
+            &config,
+            || {
+                setups += 1;
+                vec![3, 1, 2]
+            },
+            |data| {
+                // Would fail if the data was sorted by a previous run.
+                assert_eq!(*data, [3, 1, 2]);
+                data.sort_unstable();
+            },
+        );
+
+        assert_eq!(setups, config.warmup + config.iterations);
+    }
+
+    #[test]
+    fn test_bench_in_place_solves_fresh_data() {
+        let entry = SolverEntry::new::<SortingSolver, _, _>(0, 1, "SortingSolver");
+        let config = BenchConfig {
+            warmup: 2,
+            iterations: 5,
+            always_fresh: false,
+        };
+
+        let result = bench(&entry, "cba", &config).unwrap();
+
+        assert!(result.fresh);
+        assert_eq!(result.solve.iterations, 5);
+    }
+
+    #[test]
+    fn test_bench_result_to_json() {
+        let stats =
+            Stats::from_samples(vec![Duration::from_nanos(1500), Duration::from_nanos(500)]);
+        let result = BenchResult {
+            day: 1,
+            part: 2,
+            name: "Quoted\"Solver",
+            fresh: true,
+            parse: stats.clone(),
+            solve: stats,
+        };
+        let stats = "{\"iterations\":2,\"min_ns\":500,\"median_ns\":1000,\"p95_ns\":1500,\
+                     \"max_ns\":1500,\"mean_ns\":1000}";
+
+        assert_eq!(
+            result.to_json(),
+            format!(
+                "{{\"day\":1,\"part\":2,\"name\":\"Quoted\\\"Solver\",\"fresh\":true,\
+                 \"parse\":{stats},\"solve\":{stats}}}"
+            )
+        );
+    }
+}
@@ -0,0 +1,456 @@
+//! Benchmark results kept across runs to detect regressions between commits.
+//!
+//! Every benchmarked phase is appended as one line of JSON to the history
+//! file, `bench_history.jsonl` in the workspace root by default, along with
+//! the git revision and toolchain it was measured with.
+
+use std::{
+    fmt::Display,
+    path::{Path, PathBuf},
+    process::Command,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use super::{BenchResult, Stats, nanos};
+use crate::jsonl::{self, JsonlError};
+
+/// Version of the compiler `common` was built with.
+pub const TOOLCHAIN: &str = env!("AOC_RUSTC_VERSION");
+
+/// Changes with a p-value above this are considered noise.
+pub const SIGNIFICANCE: f64 = 0.01;
+
+/// Changes of the median smaller than this fraction are considered too small
+/// to matter, even when they are statistically significant.
+pub const MIN_CHANGE: f64 = 0.05;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Phase {
+    Parse,
+    Solve,
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::Solve => write!(f, "solve"),
+        }
+    }
+}
+
+/// A single benchmarked phase of a solver.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    /// When the benchmark run started, in milliseconds since the Unix epoch.
+    /// Shared by every entry recorded by the same run.
+    pub run: u64,
+    pub revision: String,
+    pub toolchain: String,
+
+    pub day: usize,
+    pub part: usize,
+    pub name: String,
+    pub phase: Phase,
+
+    pub iterations: usize,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub p95_ns: u64,
+    pub max_ns: u64,
+    pub mean_ns: u64,
+    pub samples_ns: Vec<u64>,
+}
+
+impl HistoryEntry {
+    fn new(run: &RunInfo, result: &BenchResult, phase: Phase, stats: &Stats) -> Self {
+        Self {
+            run: run.run,
+            revision: run.revision.clone(),
+            toolchain: run.toolchain.clone(),
+            day: result.day,
+            part: result.part,
+            name: result.name.to_owned(),
+            phase,
+            iterations: stats.iterations,
+            min_ns: nanos(stats.min),
+            median_ns: nanos(stats.median),
+            p95_ns: nanos(stats.p95),
+            max_ns: nanos(stats.max),
+            mean_ns: nanos(stats.mean),
+            samples_ns: stats.samples.iter().copied().map(nanos).collect(),
+        }
+    }
+
+    /// Both phases of `result`, as measured by `run`.
+    #[must_use]
+    pub fn from_result(run: &RunInfo, result: &BenchResult) -> [Self; 2] {
+        [
+            Self::new(run, result, Phase::Parse, &result.parse),
+            Self::new(run, result, Phase::Solve, &result.solve),
+        ]
+    }
+
+    /// Whether `other` measured the same phase of the same solver.
+    #[must_use]
+    pub fn same_benchmark(&self, other: &Self) -> bool {
+        self.day == other.day
+            && self.part == other.part
+            && self.name == other.name
+            && self.phase == other.phase
+    }
+}
+
+/// Describes the circumstances of a benchmark run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunInfo {
+    pub run: u64,
+    pub revision: String,
+    pub toolchain: String,
+}
+
+impl RunInfo {
+    /// Describes a run starting now on the current revision of the repository
+    /// at `repo_dir`.
+    #[must_use]
+    pub fn now(repo_dir: &Path) -> Self {
+        let run = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| {
+                u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX)
+            });
+
+        Self {
+            run,
+            revision: git_revision(repo_dir),
+            toolchain: TOOLCHAIN.to_owned(),
+        }
+    }
+}
+
+/// Short hash of the commit checked out at `repo_dir`, suffixed with `-dirty`
+/// if there are uncommitted changes, or `unknown` if git isn't available.
+#[must_use]
+pub fn git_revision(repo_dir: &Path) -> String {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .current_dir(repo_dir)
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .and_then(|output| String::from_utf8(output.stdout).ok())
+    };
+
+    let Some(revision) = git(&["rev-parse", "--short", "HEAD"]) else {
+        return "unknown".to_owned();
+    };
+
+    let dirty = git(&["status", "--porcelain", "--untracked-files=no"])
+        .is_some_and(|status| !status.trim().is_empty());
+
+    if dirty {
+        format!("{}-dirty", revision.trim())
+    } else {
+        revision.trim().to_owned()
+    }
+}
+
+/// The file benchmark results are appended to.
+#[derive(Debug, Clone)]
+pub struct History {
+    path: PathBuf,
+}
+
+impl History {
+    #[must_use]
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// `bench_history.jsonl` in the workspace root.
+    #[must_use]
+    pub fn default_path() -> PathBuf {
+        crate::input::workspace_dir().join("bench_history.jsonl")
+    }
+
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Appends `entries` to the history file, creating it if needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `HistoryError` if the history file can't be written.
+    pub fn append(&self, entries: &[HistoryEntry]) -> Result<(), HistoryError> {
+        jsonl::append(&self.path, entries)
+            .map_err(|error| HistoryError::Io(self.path.clone(), error))
+    }
+
+    /// Loads every entry in the history file, oldest first.
+    /// A missing history file is treated as empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `HistoryError` if the history file can't be read or contains
+    /// an invalid entry.
+    pub fn load(&self) -> Result<Vec<HistoryEntry>, HistoryError> {
+        jsonl::load(&self.path).map_err(|error| match error {
+            JsonlError::Io(error) => HistoryError::Io(self.path.clone(), error),
+            JsonlError::Invalid { line, error } => HistoryError::Invalid {
+                path: self.path.clone(),
+                line,
+                error,
+            },
+        })
+    }
+}
+
+#[derive(Debug)]
+pub enum HistoryError {
+    Io(PathBuf, std::io::Error),
+    Invalid {
+        path: PathBuf,
+        line: usize,
+        error: String,
+    },
+}
+
+impl Display for HistoryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HistoryError::Io(path, error) => write!(f, "{}: {error}", path.display()),
+            HistoryError::Invalid { path, line, error } => {
+                write!(
+                    f,
+                    "{}:{line}: invalid history entry: {error}",
+                    path.display()
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for HistoryError {}
+
+/// Finds the entry `current` should be compared against: the most recent
+/// entry of the same benchmark from an earlier run, restricted to revisions
+/// starting with `baseline` if given.
+#[must_use]
+pub fn find_baseline<'a>(
+    history: &'a [HistoryEntry],
+    current: &HistoryEntry,
+    baseline: Option<&str>,
+) -> Option<&'a HistoryEntry> {
+    history
+        .iter()
+        .rev()
+        .filter(|entry| entry.run < current.run && entry.same_benchmark(current))
+        .find(|entry| baseline.is_none_or(|revision| entry.revision.starts_with(revision)))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Change {
+    Regression,
+    Improvement,
+    NoChange,
+}
+
+/// How a benchmark changed compared to its baseline.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Comparison {
+    /// Relative change of the median, e.g. `0.1` for 10% slower.
+    pub change: f64,
+
+    /// Two-sided p-value of the Mann-Whitney U test on the samples.
+    pub p_value: f64,
+
+    pub verdict: Change,
+}
+
+/// Compares the samples of `current` against those of `baseline`.
+///
+/// A change is only reported if the Mann-Whitney U test finds the samples
+/// differ significantly and the median moved by at least [`MIN_CHANGE`].
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn compare(baseline: &HistoryEntry, current: &HistoryEntry) -> Comparison {
+    let change = if baseline.median_ns == 0 {
+        0.0
+    } else {
+        (current.median_ns as f64 - baseline.median_ns as f64) / baseline.median_ns as f64
+    };
+
+    let p_value = mann_whitney_p(&baseline.samples_ns, &current.samples_ns);
+
+    let verdict = if p_value > SIGNIFICANCE || change.abs() < MIN_CHANGE {
+        Change::NoChange
+    } else if change > 0.0 {
+        Change::Regression
+    } else {
+        Change::Improvement
+    };
+
+    Comparison {
+        change,
+        p_value,
+        verdict,
+    }
+}
+
+/// Two-sided p-value of the Mann-Whitney U test using the normal
+/// approximation with tie correction. Returns 1 if either sample is empty.
+#[allow(clippy::cast_precision_loss)]
+fn mann_whitney_p(first: &[u64], second: &[u64]) -> f64 {
+    if first.is_empty() || second.is_empty() {
+        return 1.0;
+    }
+
+    let mut combined = first
+        .iter()
+        .map(|&sample| (sample, true))
+        .chain(second.iter().map(|&sample| (sample, false)))
+        .collect::<Vec<_>>();
+    combined.sort_unstable_by_key(|&(sample, _)| sample);
+
+    // Assign ranks, averaging the ranks of tied samples.
+    let mut rank_sum_first = 0.0;
+    let mut tie_correction = 0.0;
+    let mut idx = 0;
+    while idx < combined.len() {
+        let tied = combined[idx..]
+            .iter()
+            .take_while(|(sample, _)| *sample == combined[idx].0)
+            .count();
+
+        let rank = idx as f64 + (tied as f64 + 1.0) / 2.0;
+        let from_first = combined[idx..idx + tied]
+            .iter()
+            .filter(|(_, is_first)| *is_first)
+            .count();
+
+        rank_sum_first += rank * from_first as f64;
+        tie_correction += (tied.pow(3) - tied) as f64;
+        idx += tied;
+    }
+
+    let n1 = first.len() as f64;
+    let n2 = second.len() as f64;
+    let n = n1 + n2;
+
+    let u = rank_sum_first - n1 * (n1 + 1.0) / 2.0;
+    let mean = n1 * n2 / 2.0;
+    let variance = n1 * n2 / 12.0 * ((n + 1.0) - tie_correction / (n * (n - 1.0)));
+
+    if variance <= 0.0 {
+        // Every sample is identical.
+        return 1.0;
+    }
+
+    let z = (u - mean).abs() / variance.sqrt();
+    erfc(z / std::f64::consts::SQRT_2)
+}
+
+/// Complementary error function, accurate to about 1e-7
+/// (Abramowitz and Stegun 7.1.26).
+fn erfc(x: f64) -> f64 {
+    let t = 1.0 / (1.0 + 0.327_591_1 * x.abs());
+    let poly = t
+        * (0.254_829_592
+            + t * (-0.284_496_736
+                + t * (1.421_413_741 + t * (-1.453_152_027 + t * 1.061_405_429))));
+    let erfc = poly * (-x * x).exp();
+
+    if x >= 0.0 { erfc } else { 2.0 - erfc }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TempDir;
+
+    fn entry(run: u64, revision: &str, samples_ns: Vec<u64>) -> HistoryEntry {
+        let mut sorted = samples_ns.clone();
+        sorted.sort_unstable();
+
+        HistoryEntry {
+            run,
+            revision: revision.to_owned(),
+            toolchain: TOOLCHAIN.to_owned(),
+            day: 1,
+            part: 1,
+            name: "Day1Part1Solver".to_owned(),
+            phase: Phase::Solve,
+            iterations: sorted.len(),
+            min_ns: sorted[0],
+            median_ns: sorted[sorted.len() / 2],
+            p95_ns: sorted[sorted.len() - 1],
+            max_ns: sorted[sorted.len() - 1],
+            mean_ns: sorted.iter().sum::<u64>() / sorted.len() as u64,
+            samples_ns,
+        }
+    }
+
+    #[test]
+    fn test_compare_detects_regression() {
+        let baseline = entry(1, "aaaaaaa", (100..150).collect());
+        let current = entry(2, "bbbbbbb", (200..250).collect());
+
+        let comparison = compare(&baseline, &current);
+        assert_eq!(comparison.verdict, Change::Regression);
+        assert!(comparison.p_value < SIGNIFICANCE);
+        assert!(compare(&current, &baseline).verdict == Change::Improvement);
+    }
+
+    #[test]
+    fn test_compare_ignores_noise() {
+        let baseline = entry(1, "aaaaaaa", (100..150).collect());
+        let current = entry(2, "bbbbbbb", (100..150).rev().collect());
+
+        let comparison = compare(&baseline, &current);
+        assert_eq!(comparison.verdict, Change::NoChange);
+        assert!(comparison.p_value > 0.99);
+    }
+
+    #[test]
+    fn test_find_baseline() {
+        let history = [
+            entry(1, "aaaaaaa", vec![1]),
+            entry(2, "bbbbbbb", vec![1]),
+            entry(3, "ccccccc", vec![1]),
+        ];
+
+        let current = &history[2];
+        assert_eq!(
+            find_baseline(&history, current, None).map(|entry| entry.run),
+            Some(2)
+        );
+        assert_eq!(
+            find_baseline(&history, current, Some("aaa")).map(|entry| entry.run),
+            Some(1)
+        );
+        assert_eq!(find_baseline(&history, current, Some("ccc")), None);
+    }
+
+    #[test]
+    fn test_history_round_trip() {
+        let dir = TempDir::new("history");
+        let history = History::new(dir.join("history.jsonl"));
+
+        assert_eq!(history.load().unwrap(), []);
+
+        let entries = [
+            entry(1, "aaaaaaa", vec![3, 1, 2]),
+            entry(2, "bbbbbbb", vec![4]),
+        ];
+        history.append(&entries[..1]).unwrap();
+        history.append(&entries[1..]).unwrap();
+
+        assert_eq!(history.load().unwrap(), entries);
+    }
+}
@@ -0,0 +1,262 @@
+//! When the puzzles of the calendar unlock.
+//!
+//! A puzzle unlocks at midnight US Eastern time on its day of December, which
+//! is 05:00 UTC since daylight saving time is over by then. Time is read from
+//! a [`Clock`] so it can be fixed in tests.
+
+use std::{
+    fmt::Display,
+    ops::RangeInclusive,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// Days of the calendar.
+pub const DAYS: RangeInclusive<usize> = 1..=25;
+
+/// How far midnight US Eastern time (UTC-5) is past midnight UTC.
+const UNLOCK_HOUR_UTC: u64 = 5;
+
+/// Tells the current time.
+pub trait Clock {
+    fn now(&self) -> SystemTime;
+}
+
+/// The time of the system.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+/// A clock stopped at a given time.
+#[derive(Debug, Clone, Copy)]
+pub struct FixedClock(pub SystemTime);
+
+impl Clock for FixedClock {
+    fn now(&self) -> SystemTime {
+        self.0
+    }
+}
+
+/// The puzzles of an event.
+#[derive(Debug, Clone)]
+pub struct Calendar<C: Clock = SystemClock> {
+    year: u32,
+    clock: C,
+}
+
+impl Calendar {
+    /// The calendar of `year` as of now.
+    #[must_use]
+    pub fn new(year: u32) -> Self {
+        Self::with_clock(year, SystemClock)
+    }
+}
+
+impl<C: Clock> Calendar<C> {
+    /// The calendar of `year` with time read from `clock`.
+    #[must_use]
+    pub fn with_clock(year: u32, clock: C) -> Self {
+        Self { year, clock }
+    }
+
+    #[must_use]
+    pub fn year(&self) -> u32 {
+        self.year
+    }
+
+    #[must_use]
+    pub fn now(&self) -> SystemTime {
+        self.clock.now()
+    }
+
+    /// When the puzzle of `day` unlocks.
+    #[must_use]
+    pub fn unlock_time(&self, day: usize) -> SystemTime {
+        let days = days_since_epoch(i64::from(self.year), 12, day as i64);
+        let seconds = u64::try_from(days).unwrap_or(0) * 24 * 60 * 60 + UNLOCK_HOUR_UTC * 60 * 60;
+
+        UNIX_EPOCH + Duration::from_secs(seconds)
+    }
+
+    /// How long until the puzzle of `day` unlocks, or `None` if it's unlocked.
+    #[must_use]
+    pub fn until_unlock(&self, day: usize) -> Option<Duration> {
+        self.unlock_time(day)
+            .duration_since(self.now())
+            .ok()
+            .filter(|remaining| !remaining.is_zero())
+    }
+
+    #[must_use]
+    pub fn is_unlocked(&self, day: usize) -> bool {
+        self.until_unlock(day).is_none()
+    }
+
+    /// Checks that `day` is part of the calendar and its puzzle is unlocked.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Unavailable::NoSuchDay` if `day` isn't one of [`DAYS`], or
+    /// `Unavailable::Locked` with the time left if it isn't unlocked yet.
+    pub fn check_unlocked(&self, day: usize) -> Result<(), Unavailable> {
+        if !DAYS.contains(&day) {
+            return Err(Unavailable::NoSuchDay(day));
+        }
+
+        match self.until_unlock(day) {
+            Some(remaining) => Err(Unavailable::Locked { day, remaining }),
+            None => Ok(()),
+        }
+    }
+
+    /// The first day of the calendar that isn't unlocked yet.
+    #[must_use]
+    pub fn next_unlock(&self) -> Option<usize> {
+        DAYS.into_iter().find(|&day| !self.is_unlocked(day))
+    }
+}
+
+/// Number of days from 1970-01-01 to the given date of the proleptic
+/// Gregorian calendar, see <http://howardhinnant.github.io/date_algorithms.html>.
+fn days_since_epoch(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Formats a countdown, e.g. `1d 2h 3m 4s`, leaving out leading zero units.
+#[must_use]
+pub fn format_countdown(remaining: Duration) -> String {
+    // Rounded up, so a countdown never shows 0s while still locked.
+    let seconds = remaining.as_secs() + u64::from(remaining.subsec_nanos() > 0);
+
+    let units = [
+        (seconds / (24 * 60 * 60), "d"),
+        (seconds / (60 * 60) % 24, "h"),
+        (seconds / 60 % 60, "m"),
+        (seconds % 60, "s"),
+    ];
+
+    let first = units
+        .iter()
+        .position(|&(amount, _)| amount > 0)
+        .unwrap_or(3);
+    units[first..]
+        .iter()
+        .map(|(amount, unit)| format!("{amount}{unit}"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Why the puzzle of a day can't be accessed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Unavailable {
+    /// The day isn't one of [`DAYS`].
+    NoSuchDay(usize),
+
+    /// The puzzle unlocks once `remaining` has passed.
+    Locked { day: usize, remaining: Duration },
+}
+
+impl Display for Unavailable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Unavailable::NoSuchDay(day) => write!(
+                f,
+                "invalid day `{day}`, expected {} to {}",
+                DAYS.start(),
+                DAYS.end()
+            ),
+            Unavailable::Locked { day, remaining } => {
+                write!(f, "day {day} unlocks in {}", format_countdown(*remaining))
+            }
+        }
+    }
+}
+
+impl std::error::Error for Unavailable {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 2024-12-01 05:00 UTC, when the first puzzle of 2024 unlocked.
+    const DAY_1_2024: u64 = 1_733_029_200;
+
+    fn calendar_at(seconds: u64) -> Calendar<FixedClock> {
+        Calendar::with_clock(2024, FixedClock(UNIX_EPOCH + Duration::from_secs(seconds)))
+    }
+
+    #[test]
+    fn test_unlock_time() {
+        let calendar = calendar_at(0);
+
+        assert_eq!(
+            calendar.unlock_time(1),
+            UNIX_EPOCH + Duration::from_secs(DAY_1_2024)
+        );
+        assert_eq!(
+            calendar.unlock_time(25),
+            UNIX_EPOCH + Duration::from_secs(DAY_1_2024 + 24 * 24 * 60 * 60)
+        );
+    }
+
+    #[test]
+    fn test_unlocks_at_midnight_eastern() {
+        let calendar = calendar_at(DAY_1_2024 - 1);
+        assert_eq!(
+            calendar.check_unlocked(1),
+            Err(Unavailable::Locked {
+                day: 1,
+                remaining: Duration::from_secs(1)
+            })
+        );
+        assert_eq!(calendar.next_unlock(), Some(1));
+
+        let calendar = calendar_at(DAY_1_2024);
+        assert!(calendar.is_unlocked(1));
+        assert!(!calendar.is_unlocked(2));
+        assert_eq!(calendar.next_unlock(), Some(2));
+
+        assert_eq!(calendar_at(DAY_1_2024 * 2).next_unlock(), None);
+    }
+
+    #[test]
+    fn test_days_outside_calendar_are_unavailable() {
+        let calendar = calendar_at(DAY_1_2024 * 2);
+        assert_eq!(calendar.check_unlocked(25), Ok(()));
+        assert_eq!(calendar.check_unlocked(0), Err(Unavailable::NoSuchDay(0)));
+        assert_eq!(calendar.check_unlocked(26), Err(Unavailable::NoSuchDay(26)));
+        assert_eq!(
+            Unavailable::NoSuchDay(26).to_string(),
+            "invalid day `26`, expected 1 to 25"
+        );
+    }
+
+    #[test]
+    fn test_format_countdown() {
+        assert_eq!(format_countdown(Duration::from_secs(0)), "0s");
+        assert_eq!(format_countdown(Duration::from_millis(1500)), "2s");
+        assert_eq!(format_countdown(Duration::from_secs(3 * 60 + 4)), "3m 4s");
+        assert_eq!(
+            format_countdown(Duration::from_secs(((24 + 2) * 60 + 3) * 60 + 4)),
+            "1d 2h 3m 4s"
+        );
+        assert_eq!(
+            Unavailable::Locked {
+                day: 6,
+                remaining: Duration::from_secs(60 * 60)
+            }
+            .to_string(),
+            "day 6 unlocks in 1h 0m 0s"
+        );
+    }
+}
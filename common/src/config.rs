@@ -0,0 +1,209 @@
+//! Settings of the workspace, read from `aoc.toml` in the workspace root.
+//!
+//! ```toml
+//! # Runtime budgets checked by `aoc run --all`, in milliseconds.
+//! [budget]
+//! total_ms = 1000
+//! # Budget of every day without one of its own.
+//! day_ms = 100
+//!
+//! [budget.days]
+//! 2 = 250
+//!
+//! # Where puzzle inputs are downloaded from, see `common::fetch`.
+//! [client]
+//! year = 2024
+//! base_url = "https://adventofcode.com"
+//! # Better kept in $AOC_SESSION than in a file under version control.
+//! session = "53616c7465645f5f..."
+//! ```
+//!
+//! Every setting is optional, as is the file itself.
+
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use serde::Deserialize;
+
+use crate::input;
+
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    #[serde(default)]
+    pub budget: Budget,
+
+    #[serde(default)]
+    pub client: ClientConfig,
+}
+
+impl Config {
+    /// Location of the configuration of this workspace.
+    #[must_use]
+    pub fn default_path() -> PathBuf {
+        input::workspace_dir().join("aoc.toml")
+    }
+
+    /// Parses the contents of a configuration file.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::Invalid` if `text` isn't valid TOML or has
+    /// unknown or mistyped settings.
+    pub fn parse(text: &str) -> Result<Self, ConfigError> {
+        let config = toml::from_str::<Self>(text)
+            .map_err(|error| ConfigError::Invalid(error.message().to_owned()))?;
+
+        if let Some(day) = config
+            .budget
+            .days
+            .keys()
+            .find(|day| day.parse::<usize>().is_err())
+        {
+            return Err(ConfigError::Invalid(format!(
+                "`budget.days.{day}` should be named after a day number"
+            )));
+        }
+
+        Ok(config)
+    }
+
+    /// Reads the configuration at `path`. A missing file is an empty configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ConfigError` if the file can't be read or parsed.
+    pub fn load(path: &Path) -> Result<Self, ConfigError> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(ConfigError::Io(path.to_owned(), error)),
+        }
+    }
+}
+
+/// How long solving may take, parsing included.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Budget {
+    /// Budget of every day together, in milliseconds.
+    pub total_ms: Option<f64>,
+
+    /// Budget of any day not listed in `days`, in milliseconds.
+    pub day_ms: Option<f64>,
+
+    /// Budgets of single days in milliseconds, keyed by day number.
+    #[serde(default)]
+    pub days: BTreeMap<String, f64>,
+}
+
+impl Budget {
+    /// Whether no budget is set at all.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.total_ms.is_none() && self.day_ms.is_none() && self.days.is_empty()
+    }
+
+    /// Budget of every day together.
+    #[must_use]
+    pub fn total(&self) -> Option<Duration> {
+        self.total_ms.map(from_millis)
+    }
+
+    /// Budget of `day`, its own or the one shared by every day.
+    #[must_use]
+    pub fn day(&self, day: usize) -> Option<Duration> {
+        self.days
+            .iter()
+            .find(|(other, _)| other.parse() == Ok(day))
+            .map(|(_, budget)| *budget)
+            .or(self.day_ms)
+            .map(from_millis)
+    }
+}
+
+/// How to reach the Advent of Code website.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ClientConfig {
+    /// Year of the event, 2024 by default.
+    pub year: Option<u32>,
+
+    /// URL of the website, e.g. that of a local server for testing.
+    pub base_url: Option<String>,
+
+    /// Value of the `session` cookie of a logged in account.
+    pub session: Option<String>,
+}
+
+fn from_millis(millis: f64) -> Duration {
+    Duration::try_from_secs_f64(millis.max(0.0) / 1000.0).unwrap_or(Duration::MAX)
+}
+
+#[derive(Debug)]
+pub enum ConfigError {
+    Io(PathBuf, std::io::Error),
+    Invalid(String),
+}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigError::Io(path, error) => write!(f, "{}: {error}", path.display()),
+            ConfigError::Invalid(error) => write!(f, "invalid configuration: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_budget() {
+        let config =
+            Config::parse("[budget]\ntotal_ms = 1000\nday_ms = 100\n\n[budget.days]\n2 = 250.5\n")
+                .unwrap();
+
+        assert_eq!(config.budget.total(), Some(Duration::from_secs(1)));
+        assert_eq!(config.budget.day(1), Some(Duration::from_millis(100)));
+        assert_eq!(config.budget.day(2), Some(Duration::from_micros(250_500)));
+        assert!(!config.budget.is_empty());
+    }
+
+    #[test]
+    fn test_parse_client() {
+        let config = Config::parse(
+            "[client]\nyear = 2023\nbase_url = \"http://127.0.0.1:8080\"\nsession = \"abc\"\n",
+        )
+        .unwrap();
+
+        assert_eq!(config.client.year, Some(2023));
+        assert_eq!(
+            config.client.base_url.as_deref(),
+            Some("http://127.0.0.1:8080")
+        );
+        assert_eq!(config.client.session.as_deref(), Some("abc"));
+    }
+
+    #[test]
+    fn test_parse_empty_config() {
+        let config = Config::parse("").unwrap();
+
+        assert_eq!(config.budget.total(), None);
+        assert_eq!(config.budget.day(1), None);
+        assert!(config.budget.is_empty());
+    }
+
+    #[test]
+    fn test_parse_invalid_config() {
+        assert!(Config::parse("[budget]\ntotal = 1000\n").is_err());
+        assert!(Config::parse("[budget.days]\nfirst = 10\n").is_err());
+    }
+}
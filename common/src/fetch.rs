@@ -0,0 +1,364 @@
+//! Downloads puzzle inputs from the Advent of Code website.
+//!
+//! Inputs differ per account, so requests are made with the `session` cookie
+//! of a logged in account, taken from `$AOC_SESSION` or the `[client]` table
+//! of `aoc.toml` (see [`config`](crate::config)). Downloaded inputs are
+//! written where [`read_input!`](crate::read_input) looks for them and are
+//! never downloaded again, to go easy on the website.
+
+use std::{
+    fmt::Display,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::{config::ClientConfig, input::InputDir, puzzle};
+
+/// Environment variable with the session cookie, taking precedence over the
+/// one in the configuration.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// The website, unless configured otherwise.
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// The year of this calendar.
+pub const DEFAULT_YEAR: u32 = 2024;
+
+/// Identifies the requests as automated and where they're made from, as
+/// asked by the website.
+pub const USER_AGENT: &str = concat!(
+    "github.com/imxela/advent-of-code-2024 (",
+    env!("CARGO_PKG_NAME"),
+    "/",
+    env!("CARGO_PKG_VERSION"),
+    ")"
+);
+
+/// A client of the Advent of Code website.
+#[derive(Debug, Clone)]
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    year: u32,
+    session: Option<String>,
+}
+
+impl Client {
+    /// A client of the website at `base_url`, for the event of `year`.
+    /// Requests needing an account fail without a `session`.
+    #[must_use]
+    pub fn new(base_url: &str, year: u32, session: Option<String>) -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(USER_AGENT)
+            .timeout(Duration::from_secs(30))
+            .build();
+
+        Self {
+            agent,
+            base_url: base_url.trim_end_matches('/').to_owned(),
+            year,
+            session,
+        }
+    }
+
+    /// A client as configured, with the session cookie in `$AOC_SESSION`
+    /// taking precedence over the configured one.
+    #[must_use]
+    pub fn from_config(config: &ClientConfig) -> Self {
+        let session = std::env::var(SESSION_VAR)
+            .ok()
+            .or_else(|| config.session.clone())
+            .map(|session| session.trim().to_owned())
+            .filter(|session| !session.is_empty());
+
+        Self::new(
+            config.base_url.as_deref().unwrap_or(DEFAULT_BASE_URL),
+            config.year.unwrap_or(DEFAULT_YEAR),
+            session,
+        )
+    }
+
+    /// URL of the website, e.g. to resolve relative links.
+    #[must_use]
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Year of the event the client is for.
+    #[must_use]
+    pub fn year(&self) -> u32 {
+        self.year
+    }
+
+    /// URL of the page of `day`, e.g. `https://adventofcode.com/2024/day/1`.
+    #[must_use]
+    pub fn day_url(&self, day: usize) -> String {
+        format!("{}/{}/day/{day}", self.base_url, self.year)
+    }
+
+    /// Downloads the input of `day` for the account of the session.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `FetchError` if there's no session or the request fails.
+    pub fn download_input(&self, day: usize) -> Result<String, FetchError> {
+        self.get(&format!("{}/input", self.day_url(day)))
+    }
+
+    /// Downloads the page of `day`, which only describes the second part
+    /// once the first one is solved by the account of the session.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `FetchError` if there's no session or the request fails.
+    pub fn download_puzzle(&self, day: usize) -> Result<String, FetchError> {
+        self.get(&self.day_url(day))
+    }
+
+    /// Makes sure the description of `day` is archived at `path`, downloading
+    /// it if it isn't or `refresh` is set, e.g. to get the second part once
+    /// the first one is solved.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `FetchError` if the description has to be downloaded and that
+    /// fails, or it can't be written.
+    pub fn fetch_puzzle(
+        &self,
+        day: usize,
+        path: &Path,
+        refresh: bool,
+    ) -> Result<Fetched, FetchError> {
+        if !refresh && path.is_file() {
+            return Ok(Fetched::Cached(path.to_owned()));
+        }
+
+        let page = self.download_puzzle(day)?;
+        write(path, &puzzle::descriptions(&page))?;
+
+        Ok(Fetched::Downloaded(path.to_owned()))
+    }
+
+    /// Makes sure the input set `input` of the day crate `inputs` belongs to
+    /// exists, downloading it if it doesn't.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `FetchError` if the input has to be downloaded and that fails,
+    /// or it can't be written.
+    pub fn fetch_input(&self, day: usize, inputs: &InputDir) -> Result<Fetched, FetchError> {
+        if let Ok(path) = inputs.resolve("input") {
+            return Ok(Fetched::Cached(path));
+        }
+
+        let input = self.download_input(day)?;
+
+        // The location searched first, so it's the one read from now on.
+        let path = inputs.candidates("input").remove(0);
+        write(&path, &input)?;
+
+        Ok(Fetched::Downloaded(path))
+    }
+
+    /// Requests `url` with the session cookie, returning the response body.
+    fn get(&self, url: &str) -> Result<String, FetchError> {
+        let request = self.agent.get(url).set("Cookie", &self.cookie()?);
+        read_response(url, request.call())
+    }
+
+    /// Posts `form` to `url` with the session cookie, returning the response body.
+    pub(crate) fn post(&self, url: &str, form: &[(&str, &str)]) -> Result<String, FetchError> {
+        let request = self.agent.post(url).set("Cookie", &self.cookie()?);
+        read_response(url, request.send_form(form))
+    }
+
+    fn cookie(&self) -> Result<String, FetchError> {
+        self.session
+            .as_ref()
+            .map(|session| format!("session={session}"))
+            .ok_or(FetchError::MissingSession)
+    }
+}
+
+/// Where an input is, and whether it had to be downloaded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fetched {
+    /// The input already existed.
+    Cached(PathBuf),
+
+    /// The input was downloaded.
+    Downloaded(PathBuf),
+}
+
+impl Fetched {
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        match self {
+            Fetched::Cached(path) | Fetched::Downloaded(path) => path,
+        }
+    }
+}
+
+fn read_response(
+    url: &str,
+    response: Result<ureq::Response, ureq::Error>,
+) -> Result<String, FetchError> {
+    let response = match response {
+        Ok(response) => response,
+        Err(ureq::Error::Status(status, response)) => {
+            return Err(FetchError::Status {
+                url: url.to_owned(),
+                status,
+                body: response.into_string().unwrap_or_default(),
+            });
+        }
+        Err(ureq::Error::Transport(error)) => {
+            return Err(FetchError::Transport(url.to_owned(), error.to_string()));
+        }
+    };
+
+    response
+        .into_string()
+        .map_err(|error| FetchError::Transport(url.to_owned(), error.to_string()))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), FetchError> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(|error| FetchError::Io(dir.to_owned(), error))?;
+    }
+
+    std::fs::write(path, contents).map_err(|error| FetchError::Io(path.to_owned(), error))
+}
+
+#[derive(Debug)]
+pub enum FetchError {
+    /// No session cookie is configured.
+    MissingSession,
+
+    /// The website responded with an error.
+    Status {
+        url: String,
+        status: u16,
+        body: String,
+    },
+
+    /// The website couldn't be reached.
+    Transport(String, String),
+
+    Io(PathBuf, std::io::Error),
+}
+
+impl Display for FetchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FetchError::MissingSession => write!(
+                f,
+                "no session cookie, set ${SESSION_VAR} or `client.session` in aoc.toml"
+            ),
+            FetchError::Status { url, status, body } => {
+                write!(f, "{url} responded with {status}")?;
+
+                match status {
+                    400 => write!(f, ", is the session cookie still valid?"),
+                    404 if body.contains("before it unlocks") => {
+                        write!(f, ", the puzzle isn't unlocked yet")
+                    }
+                    _ => Ok(()),
+                }
+            }
+            FetchError::Transport(url, error) => write!(f, "failed to request {url}: {error}"),
+            FetchError::Io(path, error) => write!(f, "failed to write {}: {error}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for FetchError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{stub_server::StubServer, testing::TempDir};
+
+    #[test]
+    fn test_fetch_input_downloads_once() {
+        let server = StubServer::start(vec![(200, "1 2\n3 4\n".to_owned())]);
+        let client = Client::new(server.url(), 2024, Some("secret".to_owned()));
+        let dir = TempDir::new("fetch");
+        let inputs = InputDir::new(dir.join("day-3"));
+
+        let fetched = client.fetch_input(3, &inputs).unwrap();
+        assert_eq!(
+            fetched,
+            Fetched::Downloaded(inputs.crate_dir().join("res/inputs/input"))
+        );
+        assert_eq!(inputs.read("input").unwrap(), "1 2\n3 4\n");
+
+        let fetched = client.fetch_input(3, &inputs).unwrap();
+        assert!(matches!(fetched, Fetched::Cached(_)));
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("GET /2024/day/3/input "));
+        assert!(requests[0].contains("session=secret"));
+        assert!(requests[0].contains(USER_AGENT));
+    }
+
+    #[test]
+    fn test_fetch_puzzle_archives_descriptions() {
+        let page = "<html><main><article class=\"day-desc\"><p>Part one</p></article>\
+                    <p>Your puzzle answer was <code>1</code>.</p></main></html>";
+        let server = StubServer::start(vec![(200, page.to_owned()), (200, page.to_owned())]);
+        let client = Client::new(server.url(), 2024, Some("secret".to_owned()));
+        let dir = TempDir::new("fetch");
+        let path = dir.join("day-3/res/description.html");
+
+        assert_eq!(
+            client.fetch_puzzle(3, &path, false).unwrap(),
+            Fetched::Downloaded(path.clone())
+        );
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "<article><p>Part one</p></article>\n"
+        );
+        assert_eq!(
+            client.fetch_puzzle(3, &path, false).unwrap(),
+            Fetched::Cached(path.clone())
+        );
+        assert_eq!(
+            client.fetch_puzzle(3, &path, true).unwrap(),
+            Fetched::Downloaded(path.clone())
+        );
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[0].starts_with("GET /2024/day/3 "));
+    }
+
+    #[test]
+    fn test_fetch_input_requires_session() {
+        let client = Client::new("http://127.0.0.1:1", 2024, None);
+        let dir = TempDir::new("fetch");
+        let inputs = InputDir::new(dir.join("day-3"));
+
+        assert!(matches!(
+            client.fetch_input(3, &inputs),
+            Err(FetchError::MissingSession)
+        ));
+        assert!(inputs.resolve("input").is_err());
+    }
+
+    #[test]
+    fn test_fetch_input_reports_locked_puzzle() {
+        let body = "Please don't repeatedly request this endpoint before it unlocks!";
+        let server = StubServer::start(vec![(404, body.to_owned())]);
+        let client = Client::new(server.url(), 2024, Some("secret".to_owned()));
+        let dir = TempDir::new("fetch");
+        let inputs = InputDir::new(dir.join("day-3"));
+
+        let error = client.fetch_input(3, &inputs).unwrap_err();
+        assert!(error.to_string().ends_with("the puzzle isn't unlocked yet"));
+        assert!(inputs.resolve("input").is_err());
+
+        server.requests();
+    }
+}
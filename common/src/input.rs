@@ -0,0 +1,237 @@
+//! Locating and reading puzzle inputs without relying on the working directory.
+//!
+//! Input sets of a day live in `res/inputs/` of its crate, e.g.
+//! `days/day-1/res/inputs/input`. Setting the `AOC_INPUT_DIR` environment
+//! variable makes `$AOC_INPUT_DIR/day-1/input` take precedence, which is handy
+//! for inputs kept outside of the repository.
+//!
+//! A day can have any number of input sets, e.g. one per account, named after
+//! their file. `input` is the default set and `example_input_p<N>` are the
+//! examples from the puzzle description for part N.
+
+use std::{
+    collections::BTreeSet,
+    fmt::Display,
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+pub use common_build::INPUT_DIR_VAR;
+
+/// The input sets of a single day crate.
+#[derive(Debug, Clone)]
+pub struct InputDir {
+    crate_dir: PathBuf,
+}
+
+impl InputDir {
+    /// Input sets of the day crate at `crate_dir`, usually
+    /// `env!("CARGO_MANIFEST_DIR")` of the day crate.
+    #[must_use]
+    pub fn new(crate_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            crate_dir: crate_dir.into(),
+        }
+    }
+
+    /// Input sets of the day crate for `day` in this workspace.
+    #[must_use]
+    pub fn for_day(day: usize) -> Self {
+        Self::new(workspace_dir().join(format!("days/day-{day}")))
+    }
+
+    /// Directory of the day crate these input sets belong to.
+    #[must_use]
+    pub fn crate_dir(&self) -> &Path {
+        &self.crate_dir
+    }
+
+    /// Every directory searched for input sets, in order of precedence.
+    #[must_use]
+    pub fn dirs(&self) -> Vec<PathBuf> {
+        common_build::input_dirs(&self.crate_dir)
+    }
+
+    /// Every location searched for the input set `name`, in order of precedence.
+    #[must_use]
+    pub fn candidates(&self, name: &str) -> Vec<PathBuf> {
+        self.dirs().into_iter().map(|dir| dir.join(name)).collect()
+    }
+
+    /// Names of every input set found in any of the searched directories,
+    /// in alphabetical order. Hidden files such as `.gitkeep` are ignored.
+    #[must_use]
+    pub fn sets(&self) -> Vec<String> {
+        let mut sets = BTreeSet::new();
+
+        for dir in self.dirs() {
+            let Ok(entries) = std::fs::read_dir(dir) else {
+                continue;
+            };
+
+            for entry in entries.flatten() {
+                if let Ok(name) = entry.file_name().into_string()
+                    && !name.starts_with('.')
+                    && entry.path().is_file()
+                {
+                    sets.insert(name);
+                }
+            }
+        }
+
+        sets.into_iter().collect()
+    }
+
+    /// Returns the path of the first existing location of the input set `name`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InputError::NotFound` listing every searched location if the
+    /// input set doesn't exist in any of them.
+    pub fn resolve(&self, name: &str) -> Result<PathBuf, InputError> {
+        let candidates = self.candidates(name);
+
+        candidates
+            .iter()
+            .find(|path| path.is_file())
+            .cloned()
+            .ok_or_else(|| InputError::NotFound {
+                name: name.to_owned(),
+                searched: candidates,
+            })
+    }
+
+    /// Reads the input set `name`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `InputError` if the input set can't be found or read.
+    pub fn read(&self, name: &str) -> Result<String, InputError> {
+        read_path(&self.resolve(name)?)
+    }
+}
+
+/// Reads an input given explicitly on the command line,
+/// either a path or `-` for standard input.
+///
+/// # Errors
+///
+/// Returns an `InputError` if the input can't be read.
+pub fn read_source(source: &str) -> Result<String, InputError> {
+    if source == "-" {
+        let mut input = String::new();
+        std::io::stdin()
+            .read_to_string(&mut input)
+            .map_err(|error| InputError::Io(PathBuf::from("<stdin>"), error))?;
+
+        return Ok(input);
+    }
+
+    read_path(Path::new(source))
+}
+
+/// Returns the part an example input set is meant for,
+/// e.g. `Some(2)` for `example_input_p2`.
+#[must_use]
+pub fn example_part(name: &str) -> Option<usize> {
+    name.strip_prefix("example_input_p")?.parse().ok()
+}
+
+fn read_path(path: &Path) -> Result<String, InputError> {
+    std::fs::read_to_string(path).map_err(|error| InputError::Io(path.to_owned(), error))
+}
+
+/// Root of the workspace `common` was built in.
+#[must_use]
+pub fn workspace_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("common is not inside a workspace")
+        .to_owned()
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    NotFound {
+        name: String,
+        searched: Vec<PathBuf>,
+    },
+    Io(PathBuf, std::io::Error),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::NotFound { name, searched } => {
+                write!(f, "input `{name}` not found, searched:")?;
+
+                for path in searched {
+                    write!(f, "\n  {}", path.display())?;
+                }
+
+                Ok(())
+            }
+            InputError::Io(path, error) => write!(f, "failed to read {}: {error}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// Reads the input set `input` of the calling day crate.
+///
+/// # Panics
+///
+/// Will panic if the input can't be found or read. Tests using it should be
+/// skipped when it's missing, see `common_build::detect_missing_inputs`.
+#[macro_export]
+macro_rules! read_input {
+    () => {
+        $crate::input::InputDir::new(env!("CARGO_MANIFEST_DIR"))
+            .read("input")
+            .unwrap_or_else(|error| panic!("{error}"))
+    };
+}
+
+/// Reads the example input of the given part of the calling day crate.
+///
+/// # Panics
+///
+/// Will panic if the input can't be found or read. Tests using it should be
+/// skipped when it's missing, see `common_build::detect_missing_inputs`.
+#[macro_export]
+macro_rules! read_example_input {
+    ($part:expr) => {
+        $crate::input::InputDir::new(env!("CARGO_MANIFEST_DIR"))
+            .read(&format!("example_input_p{}", $part))
+            .unwrap_or_else(|error| panic!("{error}"))
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_lists_searched_locations() {
+        let inputs = InputDir::new("/nonexistent/day-1");
+
+        let Err(InputError::NotFound { name, searched }) = inputs.resolve("input") else {
+            panic!("expected input to be missing");
+        };
+
+        assert_eq!(name, "input");
+        assert_eq!(
+            searched.last(),
+            Some(&PathBuf::from("/nonexistent/day-1/res/inputs/input"))
+        );
+    }
+
+    #[test]
+    fn test_for_day_points_into_workspace() {
+        let inputs = InputDir::for_day(1);
+
+        assert_eq!(inputs.crate_dir(), workspace_dir().join("days/day-1"));
+        assert!(inputs.resolve("input").is_ok());
+    }
+}
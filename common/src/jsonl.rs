@@ -0,0 +1,67 @@
+//! Files holding one JSON value per line, which only ever grow: the guess
+//! logs of the days and the benchmark history.
+
+use std::{io::Write, path::Path};
+
+use serde::{Serialize, de::DeserializeOwned};
+
+#[derive(Debug)]
+pub(crate) enum JsonlError {
+    Io(std::io::Error),
+    Invalid { line: usize, error: String },
+}
+
+/// Loads every value in the file at `path`, oldest first, skipping blank
+/// lines. A missing file is treated as empty.
+pub(crate) fn load<T: DeserializeOwned>(path: &Path) -> Result<Vec<T>, JsonlError> {
+    let text = match std::fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(error) => return Err(JsonlError::Io(error)),
+    };
+
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(idx, line)| {
+            serde_json::from_str(line).map_err(|error| JsonlError::Invalid {
+                line: idx + 1,
+                error: error.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Appends `values` to the file at `path`, one per line, creating it if
+/// needed.
+pub(crate) fn append<T: Serialize>(path: &Path, values: &[T]) -> Result<(), std::io::Error> {
+    let mut lines = String::new();
+    for value in values {
+        lines.push_str(&serde_json::to_string(value).expect("value is serializable"));
+        lines.push('\n');
+    }
+
+    std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .and_then(|mut file| file.write_all(lines.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TempDir;
+
+    #[test]
+    fn test_load_reports_invalid_line() {
+        let dir = TempDir::new("jsonl");
+        let path = dir.join("values.jsonl");
+        std::fs::write(&path, "1\n\n2\nthree\n").unwrap();
+
+        let Err(JsonlError::Invalid { line, .. }) = load::<u32>(&path) else {
+            panic!("expected an invalid line");
+        };
+        assert_eq!(line, 4);
+    }
+}
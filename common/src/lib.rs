@@ -1,29 +1,33 @@
 #![allow(clippy::cargo_common_metadata)]
 
-use std::fmt::Debug;
-
-#[must_use]
-#[allow(clippy::missing_panics_doc)]
-pub fn read_input() -> String {
-    let input_content: String =
-        std::fs::read_to_string(std::env::current_dir().unwrap().join("res/inputs/input"))
-            .expect("failed to read AoC input file!");
-
-    input_content
-}
-
-#[must_use]
-#[allow(clippy::missing_panics_doc)]
-pub fn read_example_input(part: usize) -> String {
-    let input_content: String = std::fs::read_to_string(
-        std::env::current_dir()
-            .unwrap()
-            .join(format!("res/inputs/example_input_p{part}")),
-    )
-    .expect("failed to read AoC example input file!");
-
-    input_content
-}
+// Lets `#[advent_solver]` refer to `::common` from within this crate too.
+extern crate self as common;
+
+pub mod answers;
+pub mod bench;
+pub mod calendar;
+pub mod config;
+pub mod fetch;
+pub mod input;
+pub mod ocr;
+pub mod puzzle;
+pub mod registry;
+pub mod submit;
+pub mod testing;
+
+mod answer;
+mod jsonl;
+mod parse;
+#[cfg(test)]
+mod stub_server;
+mod verdict;
+
+pub use answer::Answer;
+pub use common_macros::advent_solver;
+pub use parse::{ParseError, parse_token};
+pub use verdict::{Distance, Verdict};
+
+use std::{fmt::Debug, str::FromStr};
 
 /// Represents a solution to an Advent of Code problem.
 /// The solution should have a single implementation for both steps in a day.
@@ -38,6 +42,34 @@ impl<T: PartialEq + Debug> AdventSolution<T> {
         Self { solution }
     }
 
+    /// Compares the solution against the `expected` answer.
+    /// Ordered numeric answers also report whether they're too high or too low.
+    pub fn verify(&self, expected: &T) -> Verdict
+    where
+        T: Distance,
+    {
+        verdict::verdict(&self.solution, expected)
+    }
+
+    /// Like [`verify`](Self::verify), but for an expected answer in text form,
+    /// e.g. one read from a file. Reports `Verdict::WrongType` if `expected`
+    /// can't be parsed into the type of the solution.
+    pub fn verify_str(&self, expected: &str) -> Verdict
+    where
+        T: Distance + FromStr,
+    {
+        match expected.trim().parse::<T>() {
+            Ok(expected) => self.verify(&expected),
+            Err(_) => Verdict::WrongType {
+                expected: format!("`{}`", expected.trim()),
+                found: std::any::type_name::<T>().to_owned(),
+            },
+        }
+    }
+
+    /// Works for any answer type, use [`verify`](Self::verify) to also learn
+    /// how far off an ordered answer is.
+    ///
     /// # Panics
     ///
     /// Will panic if `other` is not equal to the solution produced by the solver.
@@ -50,24 +82,57 @@ impl<T: PartialEq + Debug> AdventSolution<T> {
     }
 }
 
+/// A single day of Advent of Code. Both parts of a day solve the same input,
+/// so it's parsed once into `Data` and shared by the solvers of each part.
+pub trait AdventDay {
+    /// The parsed puzzle input.
+    type Data: Clone;
+
+    /// Parses the given input data into the representation shared by the
+    /// solvers of every part.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ParseError` pointing at the offending part of the input
+    /// if it doesn't have the expected shape.
+    fn parse(input: &str) -> Result<Self::Data, ParseError>;
+}
+
 pub trait AdventSolver<T: PartialEq + Debug, D> {
-    /// Parses the given input data into an appropriate structure an
-    /// instance of its associated solver structure.
-    fn parse(input: &str) -> Self;
+    /// The day this solver belongs to, which parses its input.
+    type Day: AdventDay<Data = D>;
 
-    /// Attempts to solve the puzzle immutably using the parsed input data.
-    fn solve(&self) -> AdventSolution<T>;
+    /// Whether `solve` modifies the parsed data, e.g. by sorting it. Solving
+    /// such a solver a second time doesn't measure the real cost of solving,
+    /// so benchmarks re-parse the input before every solve.
+    const IN_PLACE: bool = false;
 
-    /// Return the parsed puzzle input.
-    fn data(&self) -> &D;
-}
+    /// Whether `solve` only returns a placeholder until the part is solved,
+    /// like the solvers generated by `aoc new`. The runner shows such parts
+    /// as unsolved and refuses to submit their answers.
+    const PLACEHOLDER: bool = false;
+
+    /// Parses the given input data into an instance of its associated solver
+    /// structure, using the parser of its day.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ParseError` pointing at the offending part of the input
+    /// if it doesn't have the expected shape.
+    fn parse(input: &str) -> Result<Self, ParseError>
+    where
+        Self: Sized,
+    {
+        Self::Day::parse(input).map(Self::from_data)
+    }
 
-pub trait AdventSolverMut<T: PartialEq + Debug, D> {
-    /// Parses the given input data into an appropriate structure an
-    /// instance of its associated solver structure.
-    fn parse(input: &str) -> Self;
+    /// Creates a solver from input already parsed by its day.
+    fn from_data(data: D) -> Self
+    where
+        Self: Sized;
 
-    /// Attempts to solve the puzzle mutably using the parsed input data.
+    /// Attempts to solve the puzzle using the parsed input data.
+    /// The parsed data may be modified in place while solving.
     fn solve(&mut self) -> AdventSolution<T>;
 
     /// Return the parsed puzzle input.
@@ -0,0 +1,335 @@
+//! Recognizes answers drawn in block letters, as several puzzles have them.
+//!
+//! Two fonts are used by the puzzles, told apart by their height: a small one
+//! 6 pixels tall and usually 4 wide, and a large one 10 pixels tall and 6
+//! wide. Lit pixels are `#` (or `█`), anything else is unlit. Letters are
+//! separated by at least one unlit column.
+//!
+//! ```text
+//! .##..###..
+//! #..#.#..#.
+//! #..#.###..   reads as "AB"
+//! ####.#..#.
+//! #..#.#..#.
+//! #..#.###..
+//! ```
+
+use std::fmt::Display;
+
+/// Height of the letters of the small font.
+pub const SMALL_HEIGHT: usize = 6;
+
+/// Height of the letters of the large font.
+pub const LARGE_HEIGHT: usize = 10;
+
+const SMALL_FONT: [(char, &str); 18] = [
+    ('A', ".##.\n#..#\n#..#\n####\n#..#\n#..#"),
+    ('B', "###.\n#..#\n###.\n#..#\n#..#\n###."),
+    ('C', ".##.\n#..#\n#...\n#...\n#..#\n.##."),
+    ('E', "####\n#...\n###.\n#...\n#...\n####"),
+    ('F', "####\n#...\n###.\n#...\n#...\n#..."),
+    ('G', ".##.\n#..#\n#...\n#.##\n#..#\n.###"),
+    ('H', "#..#\n#..#\n####\n#..#\n#..#\n#..#"),
+    ('I', "###\n.#.\n.#.\n.#.\n.#.\n###"),
+    ('J', "..##\n...#\n...#\n...#\n#..#\n.##."),
+    ('K', "#..#\n#.#.\n##..\n#.#.\n#.#.\n#..#"),
+    ('L', "#...\n#...\n#...\n#...\n#...\n####"),
+    ('O', ".##.\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('P', "###.\n#..#\n#..#\n###.\n#...\n#..."),
+    ('R', "###.\n#..#\n#..#\n###.\n#.#.\n#..#"),
+    ('S', ".###\n#...\n#...\n.##.\n...#\n###."),
+    ('U', "#..#\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('Y', "#...#\n#...#\n.#.#.\n..#..\n..#..\n..#.."),
+    ('Z', "####\n...#\n..#.\n.#..\n#...\n####"),
+];
+
+const LARGE_FONT: [(char, &str); 15] = [
+    (
+        'A',
+        "..##..\n.#..#.\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#",
+    ),
+    (
+        'B',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#....#\n#....#\n#....#\n#....#\n#####.",
+    ),
+    (
+        'C',
+        ".####.\n#....#\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#....#\n.####.",
+    ),
+    (
+        'E',
+        "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n######",
+    ),
+    (
+        'F',
+        "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+    ),
+    (
+        'G',
+        ".####.\n#....#\n#.....\n#.....\n#.....\n#..###\n#....#\n#....#\n#...##\n.###.#",
+    ),
+    (
+        'H',
+        "#....#\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#\n#....#",
+    ),
+    (
+        'J',
+        "...###\n....#.\n....#.\n....#.\n....#.\n....#.\n....#.\n#...#.\n#...#.\n.###..",
+    ),
+    (
+        'K',
+        "#....#\n#...#.\n#..#..\n#.#...\n##....\n##....\n#.#...\n#..#..\n#...#.\n#....#",
+    ),
+    (
+        'L',
+        "#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n######",
+    ),
+    (
+        'N',
+        "#....#\n##...#\n##...#\n#.#..#\n#.#..#\n#..#.#\n#..#.#\n#...##\n#...##\n#....#",
+    ),
+    (
+        'P',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+    ),
+    (
+        'R',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#..#..\n#...#.\n#...#.\n#....#\n#....#",
+    ),
+    (
+        'X',
+        "#....#\n#....#\n.#..#.\n.#..#.\n..##..\n..##..\n.#..#.\n.#..#.\n#....#\n#....#",
+    ),
+    (
+        'Z',
+        "######\n.....#\n.....#\n....#.\n...#..\n..#...\n.#....\n#.....\n#.....\n######",
+    ),
+];
+
+/// Decodes block letters drawn in `text`, one row of pixels per line.
+/// Blank lines before and after the letters are ignored.
+///
+/// # Errors
+///
+/// Returns an `OcrError` if the letters aren't drawn in either font.
+pub fn decode(text: &str) -> Result<String, OcrError> {
+    let grid = text
+        .lines()
+        .map(|line| {
+            line.chars()
+                .map(|pixel| matches!(pixel, '#' | '█'))
+                .collect()
+        })
+        .collect::<Vec<Vec<bool>>>();
+
+    decode_grid(&grid)
+}
+
+/// Decodes block letters drawn in `grid`, indexed by row and then column,
+/// where `true` is a lit pixel. Rows may differ in length, missing pixels
+/// are unlit. Blank rows before and after the letters are ignored.
+///
+/// # Errors
+///
+/// Returns an `OcrError` if the letters aren't drawn in either font.
+pub fn decode_grid<R: AsRef<[bool]>>(grid: &[R]) -> Result<String, OcrError> {
+    let lit_row = |row: &R| row.as_ref().contains(&true);
+    let first = grid.iter().position(lit_row).ok_or(OcrError::Empty)?;
+    let last = grid.iter().rposition(lit_row).ok_or(OcrError::Empty)?;
+    let rows = &grid[first..=last];
+
+    let font = match rows.len() {
+        SMALL_HEIGHT => &SMALL_FONT[..],
+        LARGE_HEIGHT => &LARGE_FONT[..],
+        height => return Err(OcrError::Height(height)),
+    };
+
+    let width = rows.iter().map(|row| row.as_ref().len()).max().unwrap_or(0);
+    let pixel = |row: usize, column: usize| rows[row].as_ref().get(column) == Some(&true);
+    let lit_column = |column: usize| (0..rows.len()).any(|row| pixel(row, column));
+
+    let mut text = String::new();
+    let mut unknown = Vec::new();
+    let mut column = 0;
+
+    while column < width {
+        if !lit_column(column) {
+            column += 1;
+            continue;
+        }
+
+        let start = column;
+        while column < width && lit_column(column) {
+            column += 1;
+        }
+
+        let glyph = (0..rows.len())
+            .map(|row| {
+                (start..column)
+                    .map(|column| if pixel(row, column) { '#' } else { '.' })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        match font.iter().find(|(_, pixels)| *pixels == glyph) {
+            Some((letter, _)) => text.push(*letter),
+            None => {
+                unknown.push(UnknownGlyph {
+                    index: text.chars().count(),
+                    column: start,
+                    glyph,
+                });
+                text.push('?');
+            }
+        }
+    }
+
+    if unknown.is_empty() {
+        Ok(text)
+    } else {
+        Err(OcrError::Unknown { text, unknown })
+    }
+}
+
+/// A glyph that isn't a letter of the font it's drawn in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownGlyph {
+    /// Position of the glyph among the letters, starting at 0.
+    pub index: usize,
+
+    /// Column of the grid the glyph starts at, starting at 0.
+    pub column: usize,
+
+    /// The pixels of the glyph, one line per row.
+    pub glyph: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OcrError {
+    /// Nothing is drawn.
+    Empty,
+
+    /// The letters are as tall as neither font.
+    Height(usize),
+
+    /// Some glyphs aren't known letters. `text` has a `?` in their place.
+    Unknown {
+        text: String,
+        unknown: Vec<UnknownGlyph>,
+    },
+}
+
+impl Display for OcrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OcrError::Empty => write!(f, "no letters drawn"),
+            OcrError::Height(height) => write!(
+                f,
+                "letters are {height} pixels tall, expected {SMALL_HEIGHT} or {LARGE_HEIGHT}"
+            ),
+            OcrError::Unknown { text, unknown } => {
+                write!(f, "unknown letters in `{text}`:")?;
+
+                for glyph in unknown {
+                    write!(
+                        f,
+                        "\n  letter {} at column {}:\n{}",
+                        glyph.index + 1,
+                        glyph.column,
+                        glyph.glyph
+                    )?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for OcrError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Draws `letters` of `font` next to each other, separated by `gap` columns.
+    fn draw(font: &[(char, &str)], letters: &str, gap: usize) -> String {
+        let glyphs = letters
+            .chars()
+            .map(|letter| {
+                let (_, pixels) = font.iter().find(|(other, _)| *other == letter).unwrap();
+                pixels.lines().collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        (0..glyphs[0].len())
+            .map(|row| {
+                glyphs
+                    .iter()
+                    .map(|glyph| format!("{}{}", glyph[row], ".".repeat(gap)))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn test_decode_small_font() {
+        let letters = SMALL_FONT
+            .iter()
+            .map(|(letter, _)| *letter)
+            .collect::<String>();
+
+        assert_eq!(decode(&draw(&SMALL_FONT, &letters, 1)), Ok(letters));
+    }
+
+    #[test]
+    fn test_decode_large_font() {
+        let letters = LARGE_FONT
+            .iter()
+            .map(|(letter, _)| *letter)
+            .collect::<String>();
+
+        assert_eq!(decode(&draw(&LARGE_FONT, &letters, 2)), Ok(letters));
+    }
+
+    #[test]
+    fn test_decode_ignores_blank_rows() {
+        let text = format!("\n......\n{}\n", draw(&SMALL_FONT, "HI", 1));
+
+        assert_eq!(decode(&text), Ok("HI".to_owned()));
+    }
+
+    #[test]
+    fn test_decode_grid() {
+        let grid = draw(&SMALL_FONT, "OK", 1)
+            .lines()
+            .map(|line| line.chars().map(|pixel| pixel == '#').collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        assert_eq!(decode_grid(&grid), Ok("OK".to_owned()));
+    }
+
+    #[test]
+    fn test_decode_reports_unknown_glyphs() {
+        let mut text = draw(&SMALL_FONT, "AB", 1);
+        // Turns the `B` into something else by lighting its top right pixel.
+        text.replace_range(8..9, "#");
+
+        let Err(OcrError::Unknown { text, unknown }) = decode(&text) else {
+            panic!("expected an unknown glyph");
+        };
+
+        assert_eq!(text, "A?");
+        assert_eq!(unknown.len(), 1);
+        assert_eq!(unknown[0].index, 1);
+        assert_eq!(unknown[0].column, 5);
+    }
+
+    #[test]
+    fn test_decode_unsupported_height() {
+        assert_eq!(decode("#\n#\n#"), Err(OcrError::Height(3)));
+        assert_eq!(decode("...\n..."), Err(OcrError::Empty));
+    }
+}
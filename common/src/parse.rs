@@ -0,0 +1,124 @@
+use std::{fmt::Display, str::FromStr};
+
+/// Describes where and why a puzzle input failed to parse.
+///
+/// Lines and columns are 1-based, columns are counted in characters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+
+    /// The offending token, empty if the input ended prematurely.
+    pub token: String,
+
+    /// Human readable description of what was expected instead,
+    /// e.g. "an unsigned integer".
+    pub expected: String,
+}
+
+impl ParseError {
+    #[must_use]
+    pub fn new(line: usize, column: usize, token: &str, expected: &str) -> Self {
+        Self {
+            line,
+            column,
+            token: token.to_owned(),
+            expected: expected.to_owned(),
+        }
+    }
+
+    /// Creates an error for `token`, which must be a slice of `input`.
+    /// The line and column are derived from where `token` is located in `input`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `token` does not point into `input`.
+    #[must_use]
+    pub fn at(input: &str, token: &str, expected: &str) -> Self {
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|offset| offset + token.len() <= input.len())
+            .expect("token is not a slice of the input");
+
+        let preceding = &input[..offset];
+        let line_start = preceding.rfind('\n').map_or(0, |idx| idx + 1);
+
+        Self::new(
+            preceding.matches('\n').count() + 1,
+            preceding[line_start..].chars().count() + 1,
+            token,
+            expected,
+        )
+    }
+
+    /// Renders the offending line of `input` with the token underlined, e.g.:
+    ///
+    /// ```text
+    ///    3 | 12   4x5
+    ///      |      ^^^
+    /// ```
+    #[must_use]
+    pub fn snippet(&self, input: &str) -> String {
+        // Lines are 1-based, so there's nothing to show for line 0.
+        let line = self
+            .line
+            .checked_sub(1)
+            .and_then(|index| input.lines().nth(index))
+            .unwrap_or_default();
+        let gutter = self.line.to_string().len().max(4);
+
+        format!(
+            "{:>gutter$} | {line}\n{:>gutter$} | {}{}",
+            self.line,
+            "",
+            " ".repeat(self.column.saturating_sub(1)),
+            "^".repeat(self.token.chars().count().max(1)),
+        )
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, found ",
+            self.line, self.column, self.expected
+        )?;
+
+        if self.token.is_empty() {
+            write!(f, "end of line")
+        } else {
+            write!(f, "`{}`", self.token.escape_debug())
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses `token`, a slice of `input`, into a `T`.
+///
+/// # Errors
+///
+/// Returns a `ParseError` pointing at `token` if it can't be parsed as a `T`.
+pub fn parse_token<T: FromStr>(input: &str, token: &str, expected: &str) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::at(input, token, expected))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snippet() {
+        let input = "3   4\n12   4x5\n";
+        let error = ParseError::at(input, &input[11..14], "an unsigned integer");
+
+        assert_eq!(error.snippet(input), "   2 | 12   4x5\n     |      ^^^");
+        assert_eq!(
+            ParseError::new(0, 0, "", "a number").snippet(input),
+            "   0 | \n     | ^"
+        );
+    }
+}
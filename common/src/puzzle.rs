@@ -0,0 +1,370 @@
+//! Reads the examples out of puzzle descriptions, as found on the page of a
+//! day of the Advent of Code website.
+//!
+//! The description of each part is an `<article>`. Example inputs are in
+//! `<pre><code>` blocks, and the answer to the example is usually the last
+//! emphasized code of the part, as in "the total distance is `<code><em>11
+//! </em></code>`". The description of the second part often refers back to
+//! an example of the first one instead of having its own.
+//!
+//! The descriptions are archived in `res/description.html` of the day crate
+//! to be read offline, see [`render`].
+
+pub mod render;
+
+use std::{
+    fmt::Display,
+    path::{Path, PathBuf},
+};
+
+/// The description of a single part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Part {
+    /// Indices of the blocks in the description of this part, see
+    /// [`Puzzle::blocks`].
+    pub blocks: Vec<usize>,
+
+    /// Every emphasized code in the description of this part, in order.
+    pub emphasized: Vec<String>,
+}
+
+/// The parts of a puzzle description.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Puzzle {
+    /// Every `<pre><code>` block of the page, in order, with HTML entities
+    /// decoded and markup removed.
+    pub blocks: Vec<String>,
+
+    /// The description of each part, the second one only once the first one
+    /// is solved.
+    pub parts: Vec<Part>,
+}
+
+/// The example input and answer of a part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    /// Index of the block the input was taken from.
+    pub block: usize,
+    pub input: String,
+    pub answer: Option<String>,
+}
+
+impl Puzzle {
+    /// Parses the page of a day.
+    #[must_use]
+    pub fn parse(html: &str) -> Self {
+        let mut puzzle = Puzzle::default();
+
+        for article in elements(html, "article") {
+            let mut blocks = Vec::new();
+            for pre in elements(article, "pre") {
+                blocks.push(puzzle.blocks.len());
+                puzzle.blocks.push(text(pre));
+            }
+
+            puzzle.parts.push(Part {
+                blocks,
+                emphasized: emphasized_code(article),
+            });
+        }
+
+        puzzle
+    }
+
+    /// The example of `part` (1 or 2), taken from `block` if given. Without
+    /// a block, it's the first one of the part, or of the first part if the
+    /// part has none.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PuzzleError` if the page doesn't describe `part` or has no
+    /// such block.
+    pub fn example(&self, part: usize, block: Option<usize>) -> Result<Example, PuzzleError> {
+        let description = part
+            .checked_sub(1)
+            .and_then(|index| self.parts.get(index))
+            .ok_or(PuzzleError::MissingPart(part))?;
+
+        let block = match block {
+            Some(block) if block < self.blocks.len() => block,
+            Some(block) => return Err(PuzzleError::MissingBlock(block)),
+            None => description
+                .blocks
+                .first()
+                .or_else(|| self.parts[0].blocks.first())
+                .copied()
+                .ok_or(PuzzleError::NoBlocks)?,
+        };
+
+        Ok(Example {
+            block,
+            input: self.blocks[block].clone(),
+            answer: description.emphasized.last().cloned(),
+        })
+    }
+}
+
+/// Location of the archived description of the day crate at `crate_dir`.
+#[must_use]
+pub fn archive_path(crate_dir: &Path) -> PathBuf {
+    crate_dir.join("res/description.html")
+}
+
+/// The descriptions of the parts on the page of a day, without the rest of
+/// the page, e.g. to archive them.
+#[must_use]
+pub fn descriptions(page: &str) -> String {
+    elements(page, "article")
+        .into_iter()
+        .map(|article| format!("<article>{article}</article>\n"))
+        .collect()
+}
+
+/// Returns the contents of every `tag` element in `html`, outermost only.
+fn elements<'a>(html: &'a str, tag: &str) -> Vec<&'a str> {
+    let open = format!("<{tag}");
+    let close = format!("</{tag}>");
+
+    let mut found = Vec::new();
+    let mut rest = html;
+
+    while let Some(start) = rest.find(&open) {
+        let after = &rest[start + open.len()..];
+
+        // Skips other tags sharing the prefix, e.g. `<em>` and `<emph>`.
+        if !after.starts_with(['>', ' ', '\n', '\t']) {
+            rest = after;
+            continue;
+        }
+
+        let Some(content_start) = after.find('>') else {
+            break;
+        };
+        let content = &after[content_start + 1..];
+
+        // Tracks nesting so an element containing another one of the same
+        // tag isn't cut short.
+        let mut depth = 1;
+        let mut index = 0;
+        let end = loop {
+            let next_open = content[index..].find(&open).map(|i| i + index);
+            let Some(next_close) = content[index..].find(&close).map(|i| i + index) else {
+                break content.len();
+            };
+
+            match next_open {
+                Some(next_open) if next_open < next_close => {
+                    depth += 1;
+                    index = next_open + open.len();
+                }
+                _ => {
+                    depth -= 1;
+                    index = next_close + close.len();
+                    if depth == 0 {
+                        break next_close;
+                    }
+                }
+            }
+        };
+
+        found.push(&content[..end]);
+        rest = &content[(end + close.len()).min(content.len())..];
+    }
+
+    found
+}
+
+/// Returns the text of every emphasized code in `html`, written either as
+/// `<code><em>...</em></code>` or `<em><code>...</code></em>`, in order.
+fn emphasized_code(html: &str) -> Vec<String> {
+    const MARKUP: [(&str, &str); 2] = [
+        ("<code><em>", "</em></code>"),
+        ("<em><code>", "</code></em>"),
+    ];
+
+    let mut found = Vec::new();
+    let mut rest = html;
+
+    loop {
+        let next = MARKUP
+            .iter()
+            .filter_map(|&(open, close)| Some((rest.find(open)?, open, close)))
+            .min_by_key(|&(start, _, _)| start);
+
+        let Some((start, open, close)) = next else {
+            break;
+        };
+
+        let content = &rest[start + open.len()..];
+        let Some(end) = content.find(close) else {
+            break;
+        };
+
+        found.push(text(&content[..end]).trim().to_owned());
+        rest = &content[end + close.len()..];
+    }
+
+    found
+}
+
+/// Removes the markup from `html` and decodes its entities.
+#[must_use]
+pub fn text(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    decode_entities(&text)
+}
+
+/// Decodes the HTML entities in `text`, named ones and numeric ones alike.
+#[must_use]
+pub fn decode_entities(text: &str) -> String {
+    let mut decoded = String::new();
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let entity = rest
+            .find(';')
+            .filter(|&end| end <= 10)
+            .and_then(|end| Some((entity(&rest[1..end])?, end)));
+
+        match entity {
+            Some((c, end)) => {
+                decoded.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    decoded.push_str(rest);
+    decoded
+}
+
+fn entity(name: &str) -> Option<char> {
+    match name {
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "amp" => Some('&'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        "nbsp" => Some('\u{a0}'),
+        _ => {
+            let number = name.strip_prefix('#')?;
+            let code = match number.strip_prefix(['x', 'X']) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => number.parse().ok()?,
+            };
+
+            char::from_u32(code)
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PuzzleError {
+    /// The page doesn't describe the part, e.g. part 2 before part 1 is solved.
+    MissingPart(usize),
+
+    /// There's no block at the index.
+    MissingBlock(usize),
+
+    /// The page has no blocks at all.
+    NoBlocks,
+}
+
+impl Display for PuzzleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PuzzleError::MissingPart(part) => {
+                write!(f, "the page doesn't describe part {part}")
+            }
+            PuzzleError::MissingBlock(block) => {
+                write!(f, "the page has no block {}", block + 1)
+            }
+            PuzzleError::NoBlocks => write!(f, "the page has no example blocks"),
+        }
+    }
+}
+
+impl std::error::Error for PuzzleError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 1: Historian Hysteria ---</h2>
+<p>For example:</p>
+<pre><code>3   4
+4   3
+</code></pre>
+<p>Here <em><code>1</code></em> is not the answer, the total is <code><em>11</em></code>.</p>
+<pre><code>a &lt; b &amp;&amp; <em>c</em>
+</code></pre>
+</article>
+<p>Your puzzle answer was <code>765748</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>So, for these example lists, the similarity score is <em><code>31</code></em>.</p>
+</article>
+</main>"#;
+
+    #[test]
+    fn test_parse_puzzle() {
+        let puzzle = Puzzle::parse(PAGE);
+
+        assert_eq!(puzzle.blocks, ["3   4\n4   3\n", "a < b && c\n"]);
+        assert_eq!(puzzle.parts.len(), 2);
+        assert_eq!(puzzle.parts[0].blocks, [0, 1]);
+        assert_eq!(puzzle.parts[0].emphasized, ["1", "11"]);
+        assert!(puzzle.parts[1].blocks.is_empty());
+        assert_eq!(puzzle.parts[1].emphasized, ["31"]);
+    }
+
+    #[test]
+    fn test_example_picks_block() {
+        let puzzle = Puzzle::parse(PAGE);
+
+        assert_eq!(
+            puzzle.example(1, None),
+            Ok(Example {
+                block: 0,
+                input: "3   4\n4   3\n".to_owned(),
+                answer: Some("11".to_owned()),
+            })
+        );
+        // Part 2 has no block of its own and reuses the first one.
+        assert_eq!(puzzle.example(2, None).unwrap().block, 0);
+        assert_eq!(
+            puzzle.example(2, None).unwrap().answer.as_deref(),
+            Some("31")
+        );
+        assert_eq!(puzzle.example(1, Some(1)).unwrap().input, "a < b && c\n");
+
+        assert_eq!(
+            puzzle.example(1, Some(2)),
+            Err(PuzzleError::MissingBlock(2))
+        );
+        assert_eq!(puzzle.example(3, None), Err(PuzzleError::MissingPart(3)));
+    }
+
+    #[test]
+    fn test_decode_entities() {
+        assert_eq!(decode_entities("&lt;&#62;&#x41; &amp;b &c;"), "<>A &b &c;");
+    }
+}
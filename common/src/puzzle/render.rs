@@ -0,0 +1,472 @@
+//! Renders puzzle descriptions as text for the terminal.
+//!
+//! Paragraphs and list items are wrapped to a width, code blocks are
+//! indented and kept as is, and links are numbered with the URLs listed as
+//! footnotes at the end. Emphasized text is bold with ANSI escape codes, or
+//! surrounded by `*` without them.
+
+use super::decode_entities;
+
+/// How to render a description.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Style {
+    /// Paragraphs are wrapped to this many columns.
+    pub width: usize,
+
+    /// Whether to style text with ANSI escape codes.
+    pub ansi: bool,
+}
+
+impl Default for Style {
+    fn default() -> Self {
+        Self {
+            width: 80,
+            ansi: false,
+        }
+    }
+}
+
+const BOLD: &str = "\x1b[1m";
+const NORMAL: &str = "\x1b[22m";
+
+/// Renders the description `html`. Relative links are resolved against
+/// `base_url`, e.g. `https://adventofcode.com`.
+#[must_use]
+pub fn render(html: &str, base_url: &str, style: Style) -> String {
+    let mut renderer = Renderer {
+        style,
+        base_url,
+        out: String::new(),
+        words: Vec::new(),
+        word: String::new(),
+        word_width: 0,
+        word_has_text: false,
+        lists: Vec::new(),
+        bullet: None,
+        pre: None,
+        emphasis: 0,
+        links: Vec::new(),
+        hrefs: Vec::new(),
+    };
+
+    for token in tokens(html) {
+        renderer.token(&token);
+    }
+
+    renderer.finish()
+}
+
+enum Token<'a> {
+    Text(&'a str),
+    Open(String, &'a str),
+    Close(String),
+}
+
+/// Splits `html` into text and tags, skipping comments. Tag names are
+/// lowercase, opening tags keep their attributes.
+fn tokens(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+            continue;
+        }
+
+        if rest.starts_with('<')
+            && let Some(end) = rest.find('>')
+        {
+            let tag = rest[1..end].trim_end_matches('/');
+            rest = &rest[end + 1..];
+
+            if let Some(name) = tag.strip_prefix('/') {
+                tokens.push(Token::Close(name.trim().to_ascii_lowercase()));
+            } else {
+                let (name, attributes) = tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+                tokens.push(Token::Open(name.to_ascii_lowercase(), attributes));
+            }
+            continue;
+        }
+
+        // The first character may be a `<` that doesn't start a tag, and may
+        // be more than one byte long.
+        let first = rest.chars().next().map_or(0, char::len_utf8);
+        let end = rest[first..]
+            .find('<')
+            .map_or(rest.len(), |end| end + first);
+        tokens.push(Token::Text(&rest[..end]));
+        rest = &rest[end..];
+    }
+
+    tokens
+}
+
+/// Returns the value of the attribute `name` in `attributes`.
+fn attribute(attributes: &str, name: &str) -> Option<String> {
+    let start = attributes.find(&format!("{name}=\""))? + name.len() + 2;
+    let end = attributes[start..].find('"')? + start;
+
+    Some(decode_entities(&attributes[start..end]))
+}
+
+struct Renderer<'a> {
+    style: Style,
+    base_url: &'a str,
+    out: String,
+
+    /// Words of the paragraph being rendered, styled, with their width.
+    words: Vec<(String, usize)>,
+    word: String,
+    word_width: usize,
+    word_has_text: bool,
+
+    /// Number of the next item of every open list, `None` for unordered ones.
+    lists: Vec<Option<usize>>,
+
+    /// Bullet of the list item the paragraph starts.
+    bullet: Option<String>,
+
+    /// Contents of the code block being rendered.
+    pre: Option<String>,
+
+    emphasis: usize,
+    links: Vec<String>,
+    hrefs: Vec<Option<String>>,
+}
+
+impl Renderer<'_> {
+    fn token(&mut self, token: &Token) {
+        match token {
+            Token::Text(text) => self.text(text),
+            Token::Open(name, attributes) => self.open(name, attributes),
+            Token::Close(name) => self.close(name),
+        }
+    }
+
+    fn open(&mut self, name: &str, attributes: &str) {
+        match name {
+            "p" | "h1" | "h2" | "h3" | "article" => self.paragraph(true),
+            "br" => self.paragraph(false),
+            "pre" => {
+                self.paragraph(true);
+                self.pre = Some(String::new());
+            }
+            "ul" | "ol" => {
+                self.paragraph(false);
+                self.lists.push((name == "ol").then_some(1));
+            }
+            "li" => {
+                self.paragraph(false);
+                let bullet = match self.lists.last_mut() {
+                    Some(Some(number)) => {
+                        *number += 1;
+                        format!("{}. ", *number - 1)
+                    }
+                    _ => "- ".to_owned(),
+                };
+                self.bullet = Some(bullet);
+            }
+            "em" => self.emphasize(true),
+            "a" => self.hrefs.push(attribute(attributes, "href")),
+            _ => {}
+        }
+
+        // Headings are framed by dashes already, they're only emphasized
+        // when that doesn't take any room.
+        if matches!(name, "h1" | "h2" | "h3") && self.style.ansi {
+            self.emphasize(true);
+        }
+    }
+
+    fn close(&mut self, name: &str) {
+        match name {
+            "p" | "article" => self.paragraph(true),
+            "h1" | "h2" | "h3" => {
+                if self.style.ansi {
+                    self.emphasize(false);
+                }
+                self.paragraph(true);
+            }
+            "pre" => self.code_block(),
+            "li" => self.paragraph(false),
+            "ul" | "ol" => {
+                self.paragraph(false);
+                self.lists.pop();
+                if self.lists.is_empty() {
+                    self.blank_line();
+                }
+            }
+            "em" => self.emphasize(false),
+            "a" => {
+                if let Some(Some(href)) = self.hrefs.pop() {
+                    let url = if href.starts_with('/') {
+                        format!("{}{href}", self.base_url)
+                    } else {
+                        href
+                    };
+
+                    self.links.push(url);
+                    let mark = format!("[{}]", self.links.len());
+                    self.close_mark(&mark, mark.len());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn text(&mut self, text: &str) {
+        let text = decode_entities(text);
+
+        if let Some(pre) = &mut self.pre {
+            pre.push_str(&text);
+            return;
+        }
+
+        for c in text.chars() {
+            if c.is_whitespace() && c != '\u{a0}' {
+                self.end_word();
+            } else {
+                self.word.push(c);
+                self.word_width += 1;
+                self.word_has_text = true;
+            }
+        }
+    }
+
+    fn emphasize(&mut self, start: bool) {
+        if start {
+            self.emphasis += 1;
+        } else {
+            self.emphasis = self.emphasis.saturating_sub(1);
+        }
+
+        // Only the outermost emphasis is marked.
+        if self.emphasis != usize::from(start) {
+            return;
+        }
+
+        let mark = match (self.style.ansi, start) {
+            (true, true) => BOLD,
+            (true, false) => NORMAL,
+            (false, _) => "*",
+        };
+
+        // Emphasis in code blocks would change the example without ANSI codes.
+        if let Some(pre) = &mut self.pre {
+            if self.style.ansi {
+                pre.push_str(mark);
+            }
+            return;
+        }
+
+        let width = usize::from(!self.style.ansi);
+        if start {
+            self.word.push_str(mark);
+            self.word_width += width;
+        } else {
+            self.close_mark(mark, width);
+        }
+    }
+
+    /// Adds a mark ending something, to the previous word if the current one
+    /// has no text yet, e.g. the end of an emphasis following a space.
+    fn close_mark(&mut self, mark: &str, width: usize) {
+        match self.words.last_mut() {
+            Some((last, last_width)) if !self.word_has_text => {
+                last.push_str(mark);
+                *last_width += width;
+            }
+            _ => {
+                self.word.push_str(mark);
+                self.word_width += width;
+            }
+        }
+    }
+
+    /// Ends the current word. Marks without text are kept for the next one.
+    fn end_word(&mut self) {
+        if !self.word_has_text {
+            return;
+        }
+
+        let word = std::mem::take(&mut self.word);
+        let width = std::mem::take(&mut self.word_width);
+        self.word_has_text = false;
+        self.words.push((word, width));
+    }
+
+    /// Indentation of the lines of a paragraph in the current list.
+    fn indent(&self) -> usize {
+        self.lists.len() * 2
+    }
+
+    /// Ends the paragraph being rendered, wrapping its words.
+    fn paragraph(&mut self, blank_line: bool) {
+        self.end_word();
+
+        if self.words.is_empty() {
+            return;
+        }
+
+        let indent = self.indent();
+        let (mut prefix, continuation) = match self.bullet.take() {
+            Some(bullet) => {
+                let start = indent.saturating_sub(2);
+                (
+                    format!("{}{bullet}", " ".repeat(start)),
+                    " ".repeat(start + bullet.len()),
+                )
+            }
+            None => (" ".repeat(indent), " ".repeat(indent)),
+        };
+
+        let mut line = String::new();
+        let mut line_width = 0;
+
+        for (word, width) in std::mem::take(&mut self.words) {
+            if line_width > 0 && prefix.len() + line_width + 1 + width > self.style.width {
+                self.out.push_str(&format!("{prefix}{line}\n"));
+                prefix.clone_from(&continuation);
+                line.clear();
+                line_width = 0;
+            }
+
+            if line_width > 0 {
+                line.push(' ');
+                line_width += 1;
+            }
+            line.push_str(&word);
+            line_width += width;
+        }
+
+        self.out.push_str(&format!("{prefix}{line}\n"));
+
+        if blank_line {
+            self.blank_line();
+        }
+    }
+
+    fn code_block(&mut self) {
+        let Some(pre) = self.pre.take() else {
+            return;
+        };
+
+        let indent = " ".repeat(self.indent() + 4);
+        for line in pre.trim_end_matches('\n').lines() {
+            let line = format!("{indent}{line}");
+            self.out.push_str(line.trim_end());
+            self.out.push('\n');
+        }
+
+        self.blank_line();
+    }
+
+    fn blank_line(&mut self) {
+        if !self.out.is_empty() && !self.out.ends_with("\n\n") {
+            self.out.push('\n');
+        }
+    }
+
+    fn finish(mut self) -> String {
+        self.paragraph(true);
+
+        let mut out = self.out.trim_end().to_owned();
+        out.push('\n');
+
+        if !self.links.is_empty() {
+            out.push('\n');
+            for (index, link) in self.links.iter().enumerate() {
+                out.push_str(&format!("[{}] {link}\n", index + 1));
+            }
+        }
+
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BASE_URL: &str = "https://adventofcode.com";
+
+    #[test]
+    fn test_render_paragraphs() {
+        let html = "<article><h2>--- Day 1: Test ---</h2><p>The <em>quick brown</em> fox \
+                    jumps over the lazy dog.</p></article>";
+
+        assert_eq!(
+            render(
+                html,
+                BASE_URL,
+                Style {
+                    width: 20,
+                    ansi: false
+                }
+            ),
+            "--- Day 1: Test ---\n\nThe *quick brown*\nfox jumps over the\nlazy dog.\n"
+        );
+    }
+
+    #[test]
+    fn test_render_ansi_emphasis() {
+        let html = "<h2>Day</h2><p>A <code><em>11 </em></code> total.</p>";
+
+        assert_eq!(
+            render(
+                html,
+                BASE_URL,
+                Style {
+                    width: 80,
+                    ansi: true
+                }
+            ),
+            "\x1b[1mDay\x1b[22m\n\nA \x1b[1m11\x1b[22m total.\n"
+        );
+    }
+
+    #[test]
+    fn test_render_non_ascii() {
+        let html = "<p>“Quoted” text<em>…</em>é</p><pre><code>ü &lt;\n</code></pre>";
+
+        assert_eq!(
+            render(html, BASE_URL, Style::default()),
+            "“Quoted” text*…*é\n\n    ü <\n"
+        );
+    }
+
+    #[test]
+    fn test_render_code_blocks() {
+        let html = "<p>For example:</p>\n<pre><code>3   4\n&lt;<em>x</em>&gt;\n</code></pre>\n<p>Done.</p>";
+
+        assert_eq!(
+            render(html, BASE_URL, Style::default()),
+            "For example:\n\n    3   4\n    <x>\n\nDone.\n"
+        );
+    }
+
+    #[test]
+    fn test_render_lists() {
+        let html = "<p>Steps:</p><ul><li>First <em>one</em>.</li><li>Second:<ol>\
+                    <li>nested</li><li>again</li></ol></li></ul><p>End.</p>";
+
+        assert_eq!(
+            render(html, BASE_URL, Style::default()),
+            "Steps:\n\n- First *one*.\n- Second:\n  1. nested\n  2. again\n\nEnd.\n"
+        );
+    }
+
+    #[test]
+    fn test_render_links_as_footnotes() {
+        let html = "<p>Get <a href=\"/2024/day/1/input\">your input</a> or read \
+                    <a href=\"https://en.wikipedia.org/wiki/Advent\" target=\"_blank\">this</a>.</p>";
+
+        assert_eq!(
+            render(html, BASE_URL, Style::default()),
+            "Get your input[1] or read this[2].\n\n\
+             [1] https://adventofcode.com/2024/day/1/input\n\
+             [2] https://en.wikipedia.org/wiki/Advent\n"
+        );
+    }
+}
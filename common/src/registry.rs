@@ -0,0 +1,247 @@
+//! Global registry of every implemented solver, keyed by day and part.
+//!
+//! `AdventSolver` can't be used as a trait object since `parse` returns
+//! `Self`, so solvers are registered through a type-erased
+//! [`SolverEntry`] instead. Annotate the solver implementation with
+//! [`#[advent_solver]`](crate::advent_solver), or use
+//! [`register_solver!`](crate::register_solver) next to it, to add it to the
+//! registry.
+//!
+//! The input parsed by [`SolverEntry::parse_data`] is type-erased as well, so
+//! the runner can parse a day once and hand it to every part through
+//! [`SolverEntry::with_data`].
+
+use std::{
+    any::Any,
+    fmt::{Debug, Display},
+    marker::PhantomData,
+    str::FromStr,
+};
+
+use crate::{AdventDay, AdventSolution, AdventSolver, Answer, Distance, ParseError, Verdict};
+
+#[doc(hidden)]
+pub use inventory;
+
+/// A solver created from owned data, or the data back if it didn't fit.
+type IntoSolver = Result<Box<dyn DynSolver>, Box<dyn Any>>;
+
+/// Object-safe view of a solver that has already parsed its input.
+pub trait DynSolver {
+    /// Solves the puzzle using the parsed input data.
+    fn solve(&mut self) -> Box<dyn DynSolution>;
+}
+
+/// Object-safe view of an `AdventSolution`, printable through `Display`.
+pub trait DynSolution: Display {
+    /// Compares the solution against an expected answer in text form.
+    /// See [`AdventSolution::verify_str`].
+    fn verify_str(&self, expected: &str) -> Verdict;
+
+    /// The solution as an [`Answer`], independent of the type of the solver.
+    fn answer(&self) -> Answer;
+}
+
+impl<T> DynSolution for AdventSolution<T>
+where
+    T: PartialEq + Debug + Distance + FromStr + Clone + Into<Answer>,
+{
+    fn verify_str(&self, expected: &str) -> Verdict {
+        AdventSolution::verify_str(self, expected)
+    }
+
+    fn answer(&self) -> Answer {
+        self.into()
+    }
+}
+
+/// A registered solver for a single day and part.
+#[derive(Debug)]
+pub struct SolverEntry {
+    pub day: usize,
+    pub part: usize,
+
+    /// Name of the solver type, e.g. `Day1Part1Solver`.
+    pub name: &'static str,
+
+    /// See [`AdventSolver::IN_PLACE`].
+    pub in_place: bool,
+
+    /// See [`AdventSolver::PLACEHOLDER`].
+    pub placeholder: bool,
+
+    answer_type: fn() -> &'static str,
+    parse: fn(&str) -> Result<Box<dyn DynSolver>, ParseError>,
+    parse_data: fn(&str) -> Result<Box<dyn Any>, ParseError>,
+    with_data: fn(&dyn Any) -> Option<Box<dyn DynSolver>>,
+    into_solver: fn(Box<dyn Any>) -> IntoSolver,
+}
+
+impl SolverEntry {
+    /// Entry for the solver `S` of the given day and part, usually created
+    /// through [`register_solver!`](crate::register_solver).
+    #[must_use]
+    pub const fn new<S, T, D>(day: usize, part: usize, name: &'static str) -> Self
+    where
+        S: AdventSolver<T, D> + 'static,
+        T: PartialEq + Debug + Distance + FromStr + Clone + Into<Answer> + 'static,
+        D: Clone + 'static,
+    {
+        Self {
+            day,
+            part,
+            name,
+            in_place: S::IN_PLACE,
+            placeholder: S::PLACEHOLDER,
+            answer_type: std::any::type_name::<T>,
+            parse: parse_erased::<S, T, D>,
+            parse_data: parse_data_erased::<S, T, D>,
+            with_data: with_data_erased::<S, T, D>,
+            into_solver: into_solver_erased::<S, T, D>,
+        }
+    }
+
+    /// Name of the type of the answers produced by the solver, e.g. `usize`.
+    #[must_use]
+    pub fn answer_type(&self) -> &'static str {
+        (self.answer_type)()
+    }
+
+    /// Parses `input` into a solver ready to be solved.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ParseError` if the solver failed to parse `input`.
+    pub fn parse(&self, input: &str) -> Result<Box<dyn DynSolver>, ParseError> {
+        (self.parse)(input)
+    }
+
+    /// Parses `input` with the parser of the day this solver belongs to,
+    /// without creating a solver yet. See [`with_data`](Self::with_data).
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ParseError` if the day failed to parse `input`.
+    pub fn parse_data(&self, input: &str) -> Result<Box<dyn Any>, ParseError> {
+        (self.parse_data)(input)
+    }
+
+    /// Creates a solver from a copy of `data` returned by
+    /// [`parse_data`](Self::parse_data) of any part of the same day.
+    /// Returns `None` if `data` was parsed by a different day.
+    #[must_use]
+    pub fn with_data(&self, data: &dyn Any) -> Option<Box<dyn DynSolver>> {
+        (self.with_data)(data)
+    }
+
+    /// Like [`with_data`](Self::with_data), but takes `data` instead of
+    /// copying it. Gives `data` back if it was parsed by a different day.
+    ///
+    /// # Errors
+    ///
+    /// Returns `data` if it doesn't belong to the day of this solver.
+    pub fn into_solver(&self, data: Box<dyn Any>) -> IntoSolver {
+        (self.into_solver)(data)
+    }
+}
+
+inventory::collect!(SolverEntry);
+
+/// Returns every registered solver, ordered by day and then part.
+#[must_use]
+pub fn solvers() -> Vec<&'static SolverEntry> {
+    let mut solvers = inventory::iter::<SolverEntry>().collect::<Vec<_>>();
+    solvers.sort_by_key(|entry| (entry.day, entry.part));
+    solvers
+}
+
+/// Returns the solver registered for the given day and part, if any.
+#[must_use]
+pub fn find(day: usize, part: usize) -> Option<&'static SolverEntry> {
+    inventory::iter::<SolverEntry>().find(|entry| entry.day == day && entry.part == part)
+}
+
+/// Wraps an `AdventSolver` so it can be used as a `DynSolver`.
+struct Erased<S, T, D> {
+    solver: S,
+    _marker: PhantomData<fn() -> (T, D)>,
+}
+
+impl<S, T, D> DynSolver for Erased<S, T, D>
+where
+    S: AdventSolver<T, D>,
+    T: PartialEq + Debug + Distance + FromStr + Clone + Into<Answer> + 'static,
+{
+    fn solve(&mut self) -> Box<dyn DynSolution> {
+        Box::new(self.solver.solve())
+    }
+}
+
+fn parse_erased<S, T, D>(input: &str) -> Result<Box<dyn DynSolver>, ParseError>
+where
+    S: AdventSolver<T, D> + 'static,
+    T: PartialEq + Debug + Distance + FromStr + Clone + Into<Answer> + 'static,
+    D: 'static,
+{
+    Ok(Box::new(Erased {
+        solver: S::parse(input)?,
+        _marker: PhantomData,
+    }))
+}
+
+fn parse_data_erased<S, T, D>(input: &str) -> Result<Box<dyn Any>, ParseError>
+where
+    S: AdventSolver<T, D>,
+    T: PartialEq + Debug,
+    D: 'static,
+{
+    Ok(Box::new(S::Day::parse(input)?))
+}
+
+fn with_data_erased<S, T, D>(data: &dyn Any) -> Option<Box<dyn DynSolver>>
+where
+    S: AdventSolver<T, D> + 'static,
+    T: PartialEq + Debug + Distance + FromStr + Clone + Into<Answer> + 'static,
+    D: Clone + 'static,
+{
+    let data = data.downcast_ref::<D>()?;
+
+    Some(Box::new(Erased {
+        solver: S::from_data(data.clone()),
+        _marker: PhantomData,
+    }))
+}
+
+fn into_solver_erased<S, T, D>(data: Box<dyn Any>) -> IntoSolver
+where
+    S: AdventSolver<T, D> + 'static,
+    T: PartialEq + Debug + Distance + FromStr + Clone + Into<Answer> + 'static,
+    D: 'static,
+{
+    let data = data.downcast::<D>()?;
+
+    Ok(Box::new(Erased {
+        solver: S::from_data(*data),
+        _marker: PhantomData,
+    }))
+}
+
+/// Registers a solver for the given day and part. Prefer annotating its
+/// implementation with [`#[advent_solver]`](crate::advent_solver), which
+/// expands to this.
+///
+/// ```ignore
+/// common::register_solver!(1, 1, Day1Part1Solver);
+/// ```
+#[macro_export]
+macro_rules! register_solver {
+    ($day:expr, $part:expr, $solver:ty) => {
+        $crate::registry::inventory::submit! {
+            $crate::registry::SolverEntry::new::<$solver, _, _>(
+                $day,
+                $part,
+                stringify!($solver),
+            )
+        }
+    };
+}
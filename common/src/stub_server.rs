@@ -0,0 +1,77 @@
+//! A minimal HTTP server for testing clients of the Advent of Code website
+//! without reaching it.
+
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::TcpListener,
+    thread::JoinHandle,
+};
+
+/// Serves canned responses, one per request in order, then stops.
+pub struct StubServer {
+    url: String,
+    handle: JoinHandle<Vec<String>>,
+}
+
+impl StubServer {
+    /// Starts serving `responses`, each a status code and body, on a free
+    /// local port.
+    pub fn start(responses: Vec<(u16, String)>) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = std::thread::spawn(move || {
+            let mut requests = Vec::new();
+
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                let mut request = String::new();
+                let mut length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+
+                    if let Some((name, value)) = line.split_once(':')
+                        && name.eq_ignore_ascii_case("content-length")
+                    {
+                        length = value.trim().parse().unwrap();
+                    }
+
+                    request.push_str(&line);
+                    if line.trim_end().is_empty() {
+                        break;
+                    }
+                }
+
+                let mut content = vec![0; length];
+                reader.read_exact(&mut content).unwrap();
+                request.push_str(&String::from_utf8(content).unwrap());
+                requests.push(request);
+
+                write!(
+                    stream,
+                    "HTTP/1.1 {status} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+
+            requests
+        });
+
+        Self { url, handle }
+    }
+
+    /// Base URL of the server, e.g. `http://127.0.0.1:12345`.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Waits for every response to be served and returns the requests
+    /// received, headers and body included.
+    pub fn requests(self) -> Vec<String> {
+        self.handle.join().unwrap()
+    }
+}
@@ -0,0 +1,451 @@
+//! Submits answers to the Advent of Code website.
+//!
+//! Every submission of a day is appended as one line of JSON to its guess
+//! log, `res/guesses.jsonl` of the day crate. Before submitting, the log is
+//! checked so answers that are known to be wrong, or that are out of the
+//! bounds learned from earlier "too high" and "too low" responses, aren't
+//! submitted again: wrong answers make the website wait longer and longer
+//! before accepting the next one.
+
+use std::{
+    cmp::Ordering,
+    fmt::Display,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    Answer, Distance,
+    fetch::{Client, FetchError},
+    jsonl::{self, JsonlError},
+    puzzle,
+};
+
+/// What the website made of a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Correct,
+
+    /// Wrong, without a hint in which direction.
+    Wrong,
+
+    TooHigh,
+    TooLow,
+
+    /// An answer was submitted too recently, nothing was checked.
+    RateLimited(Duration),
+
+    /// The part was already solved, nothing was checked.
+    AlreadySolved,
+}
+
+impl Outcome {
+    /// Reads the outcome from the page the website responds with, or `None`
+    /// if it isn't recognized.
+    #[must_use]
+    pub fn parse(page: &str) -> Option<Self> {
+        if page.contains("That's the right answer") {
+            return Some(Outcome::Correct);
+        }
+
+        if page.contains("not the right answer") {
+            return Some(if page.contains("too high") {
+                Outcome::TooHigh
+            } else if page.contains("too low") {
+                Outcome::TooLow
+            } else {
+                Outcome::Wrong
+            });
+        }
+
+        // e.g. "You have 1m 12s left to wait."
+        if let Some((before, _)) = page.split_once(" left to wait") {
+            let wait = before.rsplit_once("You have ")?.1;
+            return parse_wait(wait).map(Outcome::RateLimited);
+        }
+
+        if page.contains("Did you already complete it") {
+            return Some(Outcome::AlreadySolved);
+        }
+
+        None
+    }
+
+    /// Whether the answer was checked, so it's worth remembering.
+    #[must_use]
+    pub fn is_checked(&self) -> bool {
+        !matches!(self, Outcome::RateLimited(_) | Outcome::AlreadySolved)
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::Wrong => write!(f, "wrong"),
+            Outcome::TooHigh => write!(f, "too high"),
+            Outcome::TooLow => write!(f, "too low"),
+            Outcome::RateLimited(wait) => {
+                write!(f, "answered too recently, wait {}s", wait.as_secs())
+            }
+            Outcome::AlreadySolved => write!(f, "already solved"),
+        }
+    }
+}
+
+/// Parses a wait time such as `1m 12s`.
+fn parse_wait(wait: &str) -> Option<Duration> {
+    wait.split_whitespace()
+        .map(|amount| {
+            let (number, unit) = amount.split_at(amount.find(|c: char| !c.is_ascii_digit())?);
+            let number = number.parse::<u64>().ok()?;
+
+            match unit {
+                "h" => Some(number * 60 * 60),
+                "m" => Some(number * 60),
+                "s" => Some(number),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+impl Client {
+    /// Submits `answer` to `part` of `day`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `SubmitError` if the request fails or the response isn't
+    /// recognized.
+    pub fn submit(&self, day: usize, part: usize, answer: &Answer) -> Result<Outcome, SubmitError> {
+        let page = self
+            .post(
+                &format!("{}/answer", self.day_url(day)),
+                &[
+                    ("level", &part.to_string()),
+                    ("answer", &answer.to_string()),
+                ],
+            )
+            .map_err(SubmitError::Fetch)?;
+
+        Outcome::parse(&page).ok_or_else(|| SubmitError::UnknownResponse(page_text(&page)))
+    }
+}
+
+/// The text of the main part of a response page, for error messages.
+fn page_text(page: &str) -> String {
+    let main = page
+        .split_once("<main>")
+        .and_then(|(_, main)| main.split_once("</main>"))
+        .map_or(page, |(main, _)| main);
+
+    puzzle::text(main)
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// An answer submitted earlier.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Guess {
+    pub part: usize,
+    pub answer: String,
+    pub outcome: Outcome,
+}
+
+/// Why an answer isn't worth submitting.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    /// The part was solved with this answer.
+    Solved(String),
+
+    /// The same answer was wrong before.
+    KnownWrong,
+
+    /// An answer as low or lower was too high.
+    TooHigh(String),
+
+    /// An answer as high or higher was too low.
+    TooLow(String),
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::Solved(answer) => write!(f, "already solved with `{answer}`"),
+            Refusal::KnownWrong => write!(f, "already submitted and it was wrong"),
+            Refusal::TooHigh(bound) => write!(f, "`{bound}` was already too high"),
+            Refusal::TooLow(bound) => write!(f, "`{bound}` was already too low"),
+        }
+    }
+}
+
+/// The answers submitted for a single day.
+#[derive(Debug, Clone)]
+pub struct GuessLog {
+    path: PathBuf,
+}
+
+impl GuessLog {
+    #[must_use]
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// The guess log of the day crate at `crate_dir`.
+    #[must_use]
+    pub fn for_crate(crate_dir: &Path) -> Self {
+        Self::new(crate_dir.join("res/guesses.jsonl"))
+    }
+
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Loads every guess in the log, oldest first.
+    /// A missing log is treated as empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `SubmitError` if the log can't be read or contains an
+    /// invalid guess.
+    pub fn load(&self) -> Result<Vec<Guess>, SubmitError> {
+        jsonl::load(&self.path).map_err(|error| match error {
+            JsonlError::Io(error) => SubmitError::Io(self.path.clone(), error),
+            JsonlError::Invalid { line, error } => SubmitError::InvalidLog {
+                path: self.path.clone(),
+                line,
+                error,
+            },
+        })
+    }
+
+    /// Appends `guess` to the log, creating it if needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `SubmitError` if the log can't be written.
+    pub fn append(&self, guess: &Guess) -> Result<(), SubmitError> {
+        jsonl::append(&self.path, std::slice::from_ref(guess))
+            .map_err(|error| SubmitError::Io(self.path.clone(), error))
+    }
+}
+
+/// Checks whether `answer` to `part` is worth submitting given the earlier
+/// `guesses`.
+///
+/// # Errors
+///
+/// Returns a `Refusal` if the outcome of submitting `answer` is already known.
+pub fn check(guesses: &[Guess], part: usize, answer: &Answer) -> Result<(), Refusal> {
+    let mut lowest_too_high: Option<Answer> = None;
+    let mut highest_too_low: Option<Answer> = None;
+
+    for guess in guesses.iter().filter(|guess| guess.part == part) {
+        let Ok(guessed) = guess.answer.parse::<Answer>();
+
+        match guess.outcome {
+            Outcome::Correct => return Err(Refusal::Solved(guess.answer.clone())),
+            _ if guessed == *answer && guess.outcome.is_checked() => {
+                return Err(Refusal::KnownWrong);
+            }
+            Outcome::TooHigh
+                if lowest_too_high
+                    .as_ref()
+                    .is_none_or(|bound| is(&guessed, Ordering::Less, bound)) =>
+            {
+                lowest_too_high = Some(guessed);
+            }
+            Outcome::TooLow
+                if highest_too_low
+                    .as_ref()
+                    .is_none_or(|bound| is(&guessed, Ordering::Greater, bound)) =>
+            {
+                highest_too_low = Some(guessed);
+            }
+            _ => {}
+        }
+    }
+
+    if let Some(bound) = lowest_too_high
+        && !is(answer, Ordering::Less, &bound)
+        && answer.distance(&bound).is_some()
+    {
+        return Err(Refusal::TooHigh(bound.to_string()));
+    }
+
+    if let Some(bound) = highest_too_low
+        && !is(answer, Ordering::Greater, &bound)
+        && answer.distance(&bound).is_some()
+    {
+        return Err(Refusal::TooLow(bound.to_string()));
+    }
+
+    Ok(())
+}
+
+/// Whether `answer` orders as `ordering` relative to `other`.
+fn is(answer: &Answer, ordering: Ordering, other: &Answer) -> bool {
+    answer
+        .distance(other)
+        .is_some_and(|(order, _)| order == ordering)
+}
+
+#[derive(Debug)]
+pub enum SubmitError {
+    Fetch(FetchError),
+
+    /// The response page doesn't say whether the answer was right.
+    UnknownResponse(String),
+
+    Io(PathBuf, std::io::Error),
+    InvalidLog {
+        path: PathBuf,
+        line: usize,
+        error: String,
+    },
+}
+
+impl Display for SubmitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubmitError::Fetch(error) => write!(f, "{error}"),
+            SubmitError::UnknownResponse(text) => write!(f, "unrecognized response: {text}"),
+            SubmitError::Io(path, error) => write!(f, "{}: {error}", path.display()),
+            SubmitError::InvalidLog { path, line, error } => {
+                write!(f, "{}:{line}: invalid guess: {error}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for SubmitError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{stub_server::StubServer, testing::TempDir};
+
+    fn guess(part: usize, answer: &str, outcome: Outcome) -> Guess {
+        Guess {
+            part,
+            answer: answer.to_owned(),
+            outcome,
+        }
+    }
+
+    #[test]
+    fn test_parse_outcome() {
+        let page = |text: &str| format!("<main><article><p>{text}</p></article></main>");
+
+        assert_eq!(
+            Outcome::parse(&page(
+                "That's the right answer! You are one gold star closer."
+            )),
+            Some(Outcome::Correct)
+        );
+        assert_eq!(
+            Outcome::parse(&page(
+                "That's not the right answer; your answer is too high. Please wait one minute."
+            )),
+            Some(Outcome::TooHigh)
+        );
+        assert_eq!(
+            Outcome::parse(&page(
+                "That's not the right answer; your answer is too low."
+            )),
+            Some(Outcome::TooLow)
+        );
+        assert_eq!(
+            Outcome::parse(&page("That's not the right answer.")),
+            Some(Outcome::Wrong)
+        );
+        assert_eq!(
+            Outcome::parse(&page(
+                "You gave an answer too recently. You have 1m 12s left to wait."
+            )),
+            Some(Outcome::RateLimited(Duration::from_secs(72)))
+        );
+        assert_eq!(
+            Outcome::parse(&page(
+                "You don't seem to be solving the right level. Did you already complete it?"
+            )),
+            Some(Outcome::AlreadySolved)
+        );
+        assert_eq!(Outcome::parse(&page("Something else")), None);
+    }
+
+    #[test]
+    fn test_check_refuses_known_outcomes() {
+        let guesses = [
+            guess(1, "500", Outcome::TooHigh),
+            guess(1, "900", Outcome::TooHigh),
+            guess(1, "100", Outcome::TooLow),
+            guess(1, "300", Outcome::Wrong),
+            guess(2, "7", Outcome::Correct),
+        ];
+        let check = |part, answer: &str| check(&guesses, part, &answer.parse().unwrap());
+
+        assert_eq!(check(1, "250"), Ok(()));
+        assert_eq!(check(1, "300"), Err(Refusal::KnownWrong));
+        assert_eq!(check(1, "700"), Err(Refusal::TooHigh("500".to_owned())));
+        assert_eq!(check(1, "-5"), Err(Refusal::TooLow("100".to_owned())));
+        assert_eq!(check(1, "abc"), Ok(()));
+        assert_eq!(check(2, "8"), Err(Refusal::Solved("7".to_owned())));
+    }
+
+    #[test]
+    fn test_guess_log_round_trips() {
+        let dir = TempDir::new("guesses");
+        let log = GuessLog::new(dir.join("guesses.jsonl"));
+
+        assert_eq!(log.load().unwrap(), Vec::new());
+
+        let guesses = [
+            guess(1, "12", Outcome::TooLow),
+            guess(1, "HI", Outcome::Correct),
+        ];
+        for guess in &guesses {
+            log.append(guess).unwrap();
+        }
+
+        assert_eq!(log.load().unwrap(), guesses);
+    }
+
+    #[test]
+    fn test_submit_posts_answer() {
+        let page = "<main><article><p>That's not the right answer; your answer is too low.</p></article></main>";
+        let server = StubServer::start(vec![(200, page.to_owned())]);
+        let client = Client::new(server.url(), 2024, Some("secret".to_owned()));
+
+        assert_eq!(
+            client.submit(4, 2, &Answer::from(1234)).unwrap(),
+            Outcome::TooLow
+        );
+
+        let requests = server.requests();
+        assert!(requests[0].starts_with("POST /2024/day/4/answer "));
+        assert!(requests[0].contains("session=secret"));
+        assert!(requests[0].ends_with("level=2&answer=1234"));
+    }
+
+    #[test]
+    fn test_submit_reports_unknown_response() {
+        let page = "<html><main><p>Something   went\nwrong</p></main></html>";
+        let server = StubServer::start(vec![(200, page.to_owned())]);
+        let client = Client::new(server.url(), 2024, Some("secret".to_owned()));
+
+        let error = client.submit(4, 1, &Answer::from(1)).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "unrecognized response: Something went wrong"
+        );
+
+        server.requests();
+    }
+}
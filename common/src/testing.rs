@@ -0,0 +1,231 @@
+//! Tests and benchmarks shared by every day crate, see
+//! [`advent_tests!`](crate::advent_tests).
+
+use std::{
+    fmt::Debug,
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use crate::{
+    AdventDay, AdventSolver, Answer, Distance,
+    answers::read_answer,
+    bench::{self, BenchConfig},
+    input::InputDir,
+    registry::SolverEntry,
+};
+
+/// Parses the input set `set` of the day crate at `crate_dir` with the day of `S`.
+///
+/// # Panics
+///
+/// Will panic if the input set can't be read or parsed.
+#[must_use]
+pub fn parse_set<S, T, D>(crate_dir: &str, set: &str) -> D
+where
+    S: AdventSolver<T, D>,
+    T: PartialEq + Debug,
+{
+    let input = InputDir::new(crate_dir)
+        .read(set)
+        .unwrap_or_else(|error| panic!("{error}"));
+
+    S::Day::parse(&input).unwrap_or_else(|error| {
+        panic!(
+            "failed to parse `{set}`: {error}\n{}",
+            error.snippet(&input)
+        )
+    })
+}
+
+/// Solves the input set `set` with `S` and proves the solution against the
+/// answer for `part` in the manifest.
+///
+/// # Panics
+///
+/// Will panic if the input set or its answer is missing, or the solution is wrong.
+pub fn prove_set<S, T, D>(crate_dir: &str, set: &str, part: usize)
+where
+    S: AdventSolver<T, D>,
+    T: PartialEq + Debug + Distance + FromStr,
+{
+    let mut solver = S::from_data(parse_set::<S, T, D>(crate_dir, set));
+    let solution = solver.solve();
+    let expected = read_answer(crate_dir, set, part);
+
+    let verdict = solution.verify(&expected);
+    assert!(
+        verdict.is_correct(),
+        "Proof invalid: expected {expected:?} but got {solution} ({verdict})!"
+    );
+}
+
+/// How `part_<N>::bench` measures, only a few iterations to keep tests fast.
+/// Use `aoc bench` for numbers worth comparing.
+pub const BENCH_CONFIG: BenchConfig = BenchConfig {
+    warmup: 1,
+    iterations: 5,
+    always_fresh: false,
+};
+
+/// Proves `S` against the answer for `part` of the input set `input`, then
+/// benchmarks parsing and solving it with [`bench::bench`] and prints the
+/// results, e.g. with `cargo test -- --nocapture`.
+///
+/// # Panics
+///
+/// Will panic if the input set or its answer is missing, or the solution is wrong.
+pub fn bench_solver<S, T, D>(crate_dir: &str, day: usize, part: usize)
+where
+    S: AdventSolver<T, D> + 'static,
+    T: PartialEq + Debug + Distance + FromStr + Clone + Into<Answer> + 'static,
+    D: Clone + 'static,
+{
+    prove_set::<S, T, D>(crate_dir, "input", part);
+
+    let input = InputDir::new(crate_dir)
+        .read("input")
+        .unwrap_or_else(|error| panic!("{error}"));
+    let entry = SolverEntry::new::<S, T, D>(day, part, std::any::type_name::<S>());
+
+    let result = bench::bench(&entry, &input, &BENCH_CONFIG)
+        .unwrap_or_else(|error| panic!("failed to parse `input`: {error}"));
+
+    println!("parse  {}", result.parse);
+    println!("solve  {}", result.solve);
+}
+
+/// A directory for a test in the temporary directory of the system, removed
+/// along with its contents when dropped, so it's cleaned up after failing
+/// tests as well.
+#[derive(Debug)]
+pub struct TempDir {
+    path: PathBuf,
+}
+
+impl TempDir {
+    /// Creates an empty directory named after `name`, unique to this process
+    /// and call.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the directory can't be created.
+    #[must_use]
+    pub fn new(name: &str) -> Self {
+        static COUNT: AtomicUsize = AtomicUsize::new(0);
+
+        let path = std::env::temp_dir().join(format!(
+            "aoc-{name}-{}-{}",
+            std::process::id(),
+            COUNT.fetch_add(1, Ordering::Relaxed)
+        ));
+
+        // Left over by a process that was killed before cleaning up.
+        let _ = std::fs::remove_dir_all(&path);
+        std::fs::create_dir_all(&path)
+            .unwrap_or_else(|error| panic!("failed to create {}: {error}", path.display()));
+
+        Self { path }
+    }
+
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Path of `path` inside the directory.
+    #[must_use]
+    pub fn join(&self, path: impl AsRef<Path>) -> PathBuf {
+        self.path.join(path)
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.path);
+    }
+}
+
+/// Generates the tests and benchmarks of a day crate for the solvers of each
+/// part, with the expected answers taken from `res/answers.toml`:
+///
+/// - `part_<N>::example` solves `example_input_p<N>`
+/// - `part_<N>::input` solves `input`
+/// - `part_<N>::bench` benchmarks parsing and solving `input`, see [`bench_solver`]
+/// - `answers` proves every registered solver against every input set
+///
+/// Tests whose input set is missing are ignored, which needs the build script
+/// to call `common_build::detect_missing_inputs`.
+/// Meant to be used inside the test module of the day crate:
+///
+/// ```ignore
+/// #[cfg(test)]
+/// mod tests {
+///     use super::*;
+///
+///     common::advent_tests! {
+///         day: 1,
+///         part_1: Day1Part1Solver,
+///         part_2: Day1Part2Solver,
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! advent_tests {
+    (day: $day:literal, $($part:ident: $solver:ty),+ $(,)?) => {
+        $(
+            $crate::advent_tests!(@part $day, $part, $solver);
+        )+
+
+        #[test]
+        fn answers() {
+            $crate::answers::prove_registered($day, env!("CARGO_MANIFEST_DIR"));
+        }
+    };
+    (@part $day:literal, part_1, $solver:ty) => {
+        $crate::advent_tests!(
+            @tests $day, part_1, 1, "example_input_p1", "missing input set `example_input_p1`", $solver
+        );
+    };
+    (@part $day:literal, part_2, $solver:ty) => {
+        $crate::advent_tests!(
+            @tests $day, part_2, 2, "example_input_p2", "missing input set `example_input_p2`", $solver
+        );
+    };
+    (@tests $day:literal, $part:ident, $number:literal, $example:literal, $missing:literal, $solver:ty) => {
+        mod $part {
+            use super::*;
+
+            #[test]
+            #[cfg_attr(missing_input = $example, ignore = $missing)]
+            fn example() {
+                $crate::testing::prove_set::<$solver, _, _>(
+                    env!("CARGO_MANIFEST_DIR"),
+                    $example,
+                    $number,
+                );
+            }
+
+            #[test]
+            #[cfg_attr(missing_input = "input", ignore = "missing input set `input`")]
+            fn input() {
+                $crate::testing::prove_set::<$solver, _, _>(
+                    env!("CARGO_MANIFEST_DIR"),
+                    "input",
+                    $number,
+                );
+            }
+
+            #[test]
+            #[cfg_attr(missing_input = "input", ignore = "missing input set `input`")]
+            fn bench() {
+                $crate::testing::bench_solver::<$solver, _, _>(
+                    env!("CARGO_MANIFEST_DIR"),
+                    $day,
+                    $number,
+                );
+            }
+        }
+    };
+}
@@ -0,0 +1,161 @@
+use std::{cmp::Ordering, fmt::Display};
+
+/// The outcome of comparing a solution against the expected answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+
+    /// The solution is larger than the expected answer by `delta`.
+    TooHigh {
+        delta: u128,
+    },
+
+    /// The solution is smaller than the expected answer by `delta`.
+    TooLow {
+        delta: u128,
+    },
+
+    /// The expected answer can't be represented by the solution's type,
+    /// e.g. text was expected but the solver produces a number.
+    WrongType {
+        expected: String,
+        found: String,
+    },
+
+    /// The solution differs from the expected answer, but the two can't be
+    /// ordered, e.g. two different strings.
+    Mismatch {
+        expected: String,
+        found: String,
+    },
+}
+
+impl Verdict {
+    #[must_use]
+    pub fn is_correct(&self) -> bool {
+        *self == Verdict::Correct
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh { delta } => write!(f, "too high by {delta}"),
+            Verdict::TooLow { delta } => write!(f, "too low by {delta}"),
+            Verdict::WrongType { expected, found } => {
+                write!(f, "wrong type: expected {expected} but solution is {found}")
+            }
+            Verdict::Mismatch { expected, found } => {
+                write!(f, "mismatch: expected {expected} but got {found}")
+            }
+        }
+    }
+}
+
+/// Answer types that can tell how far off they are from another answer.
+///
+/// Only ordered numeric types measure a distance, everything else falls back
+/// on the default implementation and is reported as a plain mismatch.
+pub trait Distance {
+    /// Returns how `self` orders relative to `other` along with the absolute
+    /// difference between the two, or `None` if that isn't meaningful.
+    fn distance(&self, _other: &Self) -> Option<(Ordering, u128)> {
+        None
+    }
+}
+
+macro_rules! impl_distance {
+    ($($number:ty),*) => {
+        $(
+            impl Distance for $number {
+                #[allow(clippy::cast_lossless, clippy::unnecessary_cast)]
+                fn distance(&self, other: &Self) -> Option<(Ordering, u128)> {
+                    Some((self.cmp(other), self.abs_diff(*other) as u128))
+                }
+            }
+        )*
+    };
+}
+
+impl_distance!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+
+impl Distance for String {}
+impl Distance for &str {}
+impl Distance for char {}
+impl Distance for bool {}
+
+/// Compares `found` against `expected`.
+pub(crate) fn verdict<T: PartialEq + std::fmt::Debug + Distance>(
+    found: &T,
+    expected: &T,
+) -> Verdict {
+    if found == expected {
+        return Verdict::Correct;
+    }
+
+    match found.distance(expected) {
+        Some((Ordering::Greater, delta)) => Verdict::TooHigh { delta },
+        Some((Ordering::Less, delta)) => Verdict::TooLow { delta },
+        _ => Verdict::Mismatch {
+            expected: format!("{expected:?}"),
+            found: format!("{found:?}"),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::AdventSolution;
+
+    use super::*;
+
+    #[test]
+    fn test_verify_ordered() {
+        assert_eq!(AdventSolution::new(10_usize).verify(&10), Verdict::Correct);
+        assert_eq!(
+            AdventSolution::new(12_usize).verify(&10),
+            Verdict::TooHigh { delta: 2 }
+        );
+        assert_eq!(
+            AdventSolution::new(-3_i64).verify(&4),
+            Verdict::TooLow { delta: 7 }
+        );
+    }
+
+    #[test]
+    fn test_verify_unordered() {
+        assert_eq!(
+            AdventSolution::new("ABC".to_owned()).verify(&"ABD".to_owned()),
+            Verdict::Mismatch {
+                expected: "\"ABD\"".to_owned(),
+                found: "\"ABC\"".to_owned(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_prove_any_answer() {
+        AdventSolution::new((1, vec!['a'])).prove(&(1, vec!['a']));
+
+        let result = std::panic::catch_unwind(|| AdventSolution::new(Some(2)).prove(&None));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_verify_str() {
+        assert_eq!(
+            AdventSolution::new(10_usize).verify_str("8"),
+            Verdict::TooHigh { delta: 2 }
+        );
+        assert_eq!(
+            AdventSolution::new(10_usize).verify_str("EFGH"),
+            Verdict::WrongType {
+                expected: "`EFGH`".to_owned(),
+                found: "usize".to_owned(),
+            }
+        );
+    }
+}
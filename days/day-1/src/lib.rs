@@ -1,39 +1,61 @@
 #![allow(clippy::cargo_common_metadata)]
-#![feature(test, iter_map_windows)]
-
-extern crate test;
 
 use std::iter::zip;
 
-#[allow(clippy::missing_panics_doc)]
-#[must_use]
-pub fn parse_values(input_string: &str) -> (Vec<usize>, Vec<usize>) {
-    let numbers = input_string
+/// Parses each line into a pair of numbers, one for each list.
+///
+/// # Errors
+///
+/// Returns a `ParseError` if a line doesn't consist of exactly two numbers.
+pub fn parse_values(input_string: &str) -> Result<(Vec<usize>, Vec<usize>), ParseError> {
+    input_string
         .lines()
-        .flat_map(|line| {
-            line.split_whitespace().map_windows(|[left, right]| {
-                (
-                    str::parse::<usize>(left).expect("failed to parse input data to usize"),
-                    str::parse::<usize>(right).expect("failed to parse input data to usize"),
-                )
-            })
+        .map(|line| {
+            let mut tokens = line.split_whitespace();
+
+            match (tokens.next(), tokens.next(), tokens.next()) {
+                (Some(left), Some(right), None) => Ok((
+                    parse_token::<usize>(input_string, left, "an unsigned integer")?,
+                    parse_token::<usize>(input_string, right, "an unsigned integer")?,
+                )),
+                (Some(_), Some(_), Some(extra)) => {
+                    Err(ParseError::at(input_string, extra, "end of line"))
+                }
+                _ => Err(ParseError::at(
+                    input_string,
+                    &line[line.len()..],
+                    "two numbers per line",
+                )),
+            }
         })
-        .unzip();
-
-    numbers
+        .collect()
 }
 
-use common::{AdventSolver, AdventSolverMut};
+use common::{AdventDay, AdventSolver, ParseError, parse_token};
+
+pub struct Day1;
+
+impl AdventDay for Day1 {
+    type Data = (Vec<usize>, Vec<usize>);
 
-struct Day1Part1Solver {
+    fn parse(input: &str) -> Result<Self::Data, ParseError> {
+        parse_values(input)
+    }
+}
+
+pub struct Day1Part1Solver {
     data: (Vec<usize>, Vec<usize>),
 }
 
-impl AdventSolverMut<usize, (Vec<usize>, Vec<usize>)> for Day1Part1Solver {
-    fn parse(input: &str) -> Self {
-        Self {
-            data: parse_values(input),
-        }
+#[common::advent_solver(day = 1, part = 1)]
+impl AdventSolver<usize, (Vec<usize>, Vec<usize>)> for Day1Part1Solver {
+    type Day = Day1;
+
+    // Both lists are sorted in place.
+    const IN_PLACE: bool = true;
+
+    fn from_data(data: (Vec<usize>, Vec<usize>)) -> Self {
+        Self { data }
     }
 
     fn solve(&mut self) -> common::AdventSolution<usize> {
@@ -61,18 +83,19 @@ impl AdventSolverMut<usize, (Vec<usize>, Vec<usize>)> for Day1Part1Solver {
     }
 }
 
-struct Day1Part2Solver {
+pub struct Day1Part2Solver {
     data: (Vec<usize>, Vec<usize>),
 }
 
+#[common::advent_solver(day = 1, part = 2)]
 impl AdventSolver<usize, (Vec<usize>, Vec<usize>)> for Day1Part2Solver {
-    fn parse(input: &str) -> Self {
-        Self {
-            data: parse_values(input),
-        }
+    type Day = Day1;
+
+    fn from_data(data: (Vec<usize>, Vec<usize>)) -> Self {
+        Self { data }
     }
 
-    fn solve(&self) -> common::AdventSolution<usize> {
+    fn solve(&mut self) -> common::AdventSolution<usize> {
         let (left, right) = &self.data;
 
         let sum: usize = left
@@ -99,49 +122,18 @@ impl AdventSolver<usize, (Vec<usize>, Vec<usize>)> for Day1Part2Solver {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_day_1_part_1_example() {
-        let mut solver = Day1Part1Solver::parse(&common::read_example_input(1));
-        let solution = solver.solve();
-        solution.prove(&11);
+    common::advent_tests! {
+        day: 1,
+        part_1: Day1Part1Solver,
+        part_2: Day1Part2Solver,
     }
 
     #[test]
-    fn test_day_1_part_1() {
-        let mut solver = Day1Part1Solver::parse(&common::read_input());
-        let solution = solver.solve();
-        solution.prove(&765_748);
-    }
-
-    #[bench]
-    fn bench_day_1_part_1(bencher: &mut test::Bencher) {
-        let mut solver = Day1Part1Solver::parse(&common::read_input());
-        bencher.iter(|| {
-            let solution = solver.solve();
-            solution.prove(&765_748);
-        });
-    }
-
-    #[test]
-    fn test_day_1_part_2_example() {
-        let solver = Day1Part2Solver::parse(&common::read_example_input(2));
-        let solution = solver.solve();
-        solution.prove(&31);
-    }
-
-    #[test]
-    fn test_day_1_part_2() {
-        let solver = Day1Part2Solver::parse(&common::read_input());
-        let solution = solver.solve();
-        solution.prove(&27_732_508);
-    }
+    fn test_day_1_parse_error() {
+        let error = Day1::parse("3   4\n2   5x\n").err().unwrap();
+        assert_eq!(error, ParseError::new(2, 5, "5x", "an unsigned integer"));
 
-    #[bench]
-    fn bench_day_1_part_2(bencher: &mut test::Bencher) {
-        let solver = Day1Part2Solver::parse(&common::read_input());
-        bencher.iter(|| {
-            let solution = solver.solve();
-            solution.prove(&27_732_508);
-        });
+        let error = Day1::parse("3   4\n2\n").err().unwrap();
+        assert_eq!(error, ParseError::new(2, 2, "", "two numbers per line"));
     }
 }
@@ -1,25 +1,23 @@
 #![allow(clippy::cargo_common_metadata)]
-#![feature(test, iter_map_windows)]
-
-extern crate test;
 
 use std::{cmp::Ordering, collections::BTreeMap};
 
-use common::{AdventSolver, AdventSolverMut};
+use common::{AdventDay, AdventSolver, ParseError, parse_token};
 
-#[allow(clippy::missing_panics_doc)]
-#[must_use]
-pub fn parse_values(input_string: &str) -> Vec<Vec<usize>> {
-    let numbers = input_string
+/// Parses each line into a report of levels.
+///
+/// # Errors
+///
+/// Returns a `ParseError` if a level isn't a number.
+pub fn parse_values(input_string: &str) -> Result<Vec<Vec<usize>>, ParseError> {
+    input_string
         .lines()
         .map(|line| {
             line.split_whitespace()
-                .map(|num| str::parse::<usize>(num).expect("failed to parse usize"))
+                .map(|num| parse_token(input_string, num, "an unsigned integer"))
                 .collect()
         })
-        .collect();
-
-    numbers
+        .collect()
 }
 
 fn validate_incrementing(levels: &[usize]) -> bool {
@@ -52,7 +50,8 @@ enum SequenceType {
 }
 
 // Finds out if the levels are more frequently incrementing, decrementing or equal.
-fn find_sequence_type(levels: &[usize]) -> SequenceType {
+// A report with fewer than two levels has no pairs to compare, so it's `None`.
+fn find_sequence_type(levels: &[usize]) -> Option<SequenceType> {
     let mut counts = BTreeMap::new();
     for sequence in levels
         .windows(2)
@@ -65,13 +64,17 @@ fn find_sequence_type(levels: &[usize]) -> SequenceType {
         *counts.entry(sequence).or_insert(0) += 1;
     }
 
-    let (most_common, _) = counts.into_iter().max_by_key(|&(_, count)| count).unwrap();
-
-    most_common
+    counts
+        .into_iter()
+        .max_by_key(|&(_, count)| count)
+        .map(|(most_common, _)| most_common)
 }
 
 fn validate_levels(levels: &[usize], filter: bool) -> bool {
-    let sequence_type = find_sequence_type(levels);
+    // A single level, e.g. left after removing one, is always safe.
+    let Some(sequence_type) = find_sequence_type(levels) else {
+        return true;
+    };
 
     for idx in 0..(levels.len() - 1) {
         let diff = levels[idx].abs_diff(levels[idx + 1]);
@@ -104,24 +107,42 @@ fn validate_levels(levels: &[usize], filter: bool) -> bool {
     true
 }
 
-struct Day2Part1Solver {
+pub struct Day2;
+
+impl AdventDay for Day2 {
+    type Data = Vec<Vec<usize>>;
+
+    fn parse(input: &str) -> Result<Self::Data, ParseError> {
+        parse_values(input)
+    }
+}
+
+pub struct Day2Part1Solver {
     data: Vec<Vec<usize>>,
 }
 
+#[common::advent_solver(day = 2, part = 1)]
 impl AdventSolver<usize, Vec<Vec<usize>>> for Day2Part1Solver {
-    fn parse(input: &str) -> Self {
-        Self {
-            data: parse_values(input),
-        }
+    type Day = Day2;
+
+    fn from_data(data: Vec<Vec<usize>>) -> Self {
+        Self { data }
     }
 
-    fn solve(&self) -> common::AdventSolution<usize> {
+    fn solve(&mut self) -> common::AdventSolution<usize> {
         self.data
             .iter()
-            .filter(|levels| match levels[0].cmp(&levels[1]) {
-                Ordering::Greater => validate_decrementing(levels),
-                Ordering::Less => validate_incrementing(levels),
-                Ordering::Equal => false,
+            .filter(|levels| {
+                // A single level is always safe.
+                if levels.len() < 2 {
+                    return true;
+                }
+
+                match levels[0].cmp(&levels[1]) {
+                    Ordering::Greater => validate_decrementing(levels),
+                    Ordering::Less => validate_incrementing(levels),
+                    Ordering::Equal => false,
+                }
             })
             .count()
             .into()
@@ -132,15 +153,16 @@ impl AdventSolver<usize, Vec<Vec<usize>>> for Day2Part1Solver {
     }
 }
 
-struct Day2Part2Solver {
+pub struct Day2Part2Solver {
     data: Vec<Vec<usize>>,
 }
 
-impl AdventSolverMut<usize, Vec<Vec<usize>>> for Day2Part2Solver {
-    fn parse(input: &str) -> Self {
-        Self {
-            data: parse_values(input),
-        }
+#[common::advent_solver(day = 2, part = 2)]
+impl AdventSolver<usize, Vec<Vec<usize>>> for Day2Part2Solver {
+    type Day = Day2;
+
+    fn from_data(data: Vec<Vec<usize>>) -> Self {
+        Self { data }
     }
 
     fn solve(&mut self) -> common::AdventSolution<usize> {
@@ -168,49 +190,27 @@ pub fn day_2_part_2(input: &mut [Vec<usize>]) -> usize {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_day_2_part_1_example() {
-        let solver = Day2Part1Solver::parse(&common::read_example_input(1));
-        let solution = solver.solve();
-        solution.prove(&2);
+    common::advent_tests! {
+        day: 2,
+        part_1: Day2Part1Solver,
+        part_2: Day2Part2Solver,
     }
 
     #[test]
-    fn test_day_2_part_1() {
-        let solver = Day2Part1Solver::parse(&common::read_input());
-        let solution = solver.solve();
-        solution.prove(&502);
-    }
-
-    #[bench]
-    fn bench_day_2_part_1(bencher: &mut test::Bencher) {
-        let solver = Day2Part1Solver::parse(&common::read_input());
-        bencher.iter(|| {
-            let solution = solver.solve();
-            solution.prove(&502);
-        });
-    }
-
-    #[test]
-    fn test_day_2_part_2_example() {
-        let mut solver = Day2Part2Solver::parse(&common::read_example_input(2));
-        let solution = solver.solve();
-        solution.prove(&4);
+    fn test_day_2_parse_error() {
+        let error = Day2::parse("1 2\n5 x\n").err().unwrap();
+        assert_eq!(error, ParseError::new(2, 3, "x", "an unsigned integer"));
     }
 
     #[test]
-    fn test_day_2_part_2() {
-        let mut solver = Day2Part2Solver::parse(&common::read_input());
-        let solution = solver.solve();
-        solution.prove(&544);
-    }
-
-    #[bench]
-    fn bench_day_2_part_2(bencher: &mut test::Bencher) {
-        let mut solver = Day2Part2Solver::parse(&common::read_input());
-        bencher.iter(|| {
-            let solution = solver.solve();
-            solution.prove(&544);
-        });
+    fn test_day_2_short_reports() {
+        Day2Part1Solver::parse("5\n5 5\n")
+            .unwrap()
+            .solve()
+            .prove(&1);
+        Day2Part2Solver::parse("5\n5 5\n")
+            .unwrap()
+            .solve()
+            .prove(&2);
     }
 }
@@ -1,11 +1,27 @@
 #![allow(clippy::cargo_common_metadata)]
-#![feature(test, iter_next_chunk)]
+#![feature(iter_next_chunk)]
 
-extern crate test;
+use common::{AdventDay, AdventSolver, ParseError};
 
-use common::AdventSolver;
+pub struct Day3;
 
-struct Day3Part1Solver {
+impl AdventDay for Day3 {
+    type Data = Vec<char>;
+
+    fn parse(input: &str) -> Result<Self::Data, ParseError> {
+        if input.is_empty() {
+            return Err(ParseError::at(input, input, "some corrupted memory"));
+        }
+
+        Ok(input
+            .as_bytes()
+            .iter()
+            .map(|byte| char::from(*byte))
+            .collect::<Vec<char>>())
+    }
+}
+
+pub struct Day3Part1Solver {
     data: Vec<char>,
 }
 
@@ -14,27 +30,24 @@ struct Mul {
     right: usize,
 }
 
+#[common::advent_solver(day = 3, part = 1)]
 impl AdventSolver<usize, Vec<char>> for Day3Part1Solver {
-    fn parse(input: &str) -> Self {
-        Day3Part1Solver {
-            data: input
-                .as_bytes()
-                .iter()
-                .map(|byte| char::from(*byte))
-                .collect::<Vec<char>>(),
-        }
+    type Day = Day3;
+
+    fn from_data(data: Vec<char>) -> Self {
+        Day3Part1Solver { data }
     }
 
-    fn solve(&self) -> common::AdventSolution<usize> {
+    fn solve(&mut self) -> common::AdventSolution<usize> {
         let mut muls: Vec<Mul> = Vec::new();
         let mut idx = 0; // Current parser index
         let data = &self.data;
 
         loop {
-            if data[idx] == 'm' {
-                if let Some(mul) = parse_op_mul(&data[idx..]) {
-                    muls.push(mul);
-                }
+            if data[idx] == 'm'
+                && let Some(mul) = parse_op_mul(&data[idx..])
+            {
+                muls.push(mul);
             }
 
             idx += 1;
@@ -54,32 +67,30 @@ impl AdventSolver<usize, Vec<char>> for Day3Part1Solver {
     }
 }
 
-struct Day3Part2Solver {
+pub struct Day3Part2Solver {
     data: Vec<char>,
 }
 
+#[common::advent_solver(day = 3, part = 2)]
 impl AdventSolver<usize, Vec<char>> for Day3Part2Solver {
-    fn parse(input: &str) -> Self {
-        Day3Part2Solver {
-            data: input
-                .as_bytes()
-                .iter()
-                .map(|byte| char::from(*byte))
-                .collect::<Vec<char>>(),
-        }
+    type Day = Day3;
+
+    fn from_data(data: Vec<char>) -> Self {
+        Day3Part2Solver { data }
     }
 
-    fn solve(&self) -> common::AdventSolution<usize> {
+    fn solve(&mut self) -> common::AdventSolution<usize> {
         let mut muls: Vec<Mul> = Vec::new();
         let mut idx = 0; // Current parser index
         let mut ignore_muls = false;
         let data = &self.data;
 
         loop {
-            if data[idx] == 'm' && !ignore_muls {
-                if let Some(mul) = parse_op_mul(&data[idx..]) {
-                    muls.push(mul);
-                }
+            if data[idx] == 'm'
+                && !ignore_muls
+                && let Some(mul) = parse_op_mul(&data[idx..])
+            {
+                muls.push(mul);
             }
 
             if data[idx] == 'd' {
@@ -137,7 +148,6 @@ fn parse_op_mul(data: &[char]) -> Option<Mul> {
                                         [4 + left_digits.len() + 1 + right_digits.len()..]
                                         .iter()
                                         .next()
-                                        .take()
                                     {
                                         if elem == &')' {
                                             return Some(Mul { left, right });
@@ -175,55 +185,15 @@ fn parse_op_do(data: &[char]) -> bool {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_day_3_part_1_example() {
-        let input = common::read_example_input(1);
-        let solver = Day3Part1Solver::parse(&input);
-        let solution = solver.solve();
-        solution.prove(&161);
-    }
-
-    #[test]
-    fn test_day_3_part_1() {
-        let input = common::read_input();
-        let solver = Day3Part1Solver::parse(&input);
-        let solution = solver.solve();
-        solution.prove(&153_469_856);
-    }
-
-    #[bench]
-    fn bench_day_3_part_1(bencher: &mut test::Bencher) {
-        let input = common::read_input();
-        let solver = Day3Part1Solver::parse(&input);
-        bencher.iter(|| {
-            let solution = solver.solve();
-            solution.prove(&153_469_856);
-        });
-    }
-
-    #[test]
-    fn test_day_3_part_2_example() {
-        let input = common::read_example_input(2);
-        let solver = Day3Part2Solver::parse(&input);
-        let solution = solver.solve();
-        solution.prove(&48);
+    common::advent_tests! {
+        day: 3,
+        part_1: Day3Part1Solver,
+        part_2: Day3Part2Solver,
     }
 
     #[test]
-    fn test_day_3_part_2() {
-        let input = common::read_input();
-        let solver = Day3Part2Solver::parse(&input);
-        let solution = solver.solve();
-        solution.prove(&77_055_967);
-    }
-
-    #[bench]
-    fn bench_day_3_part_2(bencher: &mut test::Bencher) {
-        let input = common::read_input();
-        let solver = Day3Part2Solver::parse(&input);
-        bencher.iter(|| {
-            let solution = solver.solve();
-            solution.prove(&77_055_967);
-        });
+    fn test_day_3_parse_error() {
+        let error = Day3::parse("").err().unwrap();
+        assert_eq!(error, ParseError::new(1, 1, "", "some corrupted memory"));
     }
 }
@@ -0,0 +1,3 @@
+fn main() {
+    common_build::detect_missing_inputs();
+}
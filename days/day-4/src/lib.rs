@@ -1,23 +1,31 @@
 #![allow(clippy::cargo_common_metadata)]
 #![allow(clippy::collapsible_if)] // For clarity
-#![feature(test)]
 
-extern crate test;
+use common::{AdventDay, AdventSolver, ParseError};
 
-use common::AdventSolver;
+pub struct Day4;
 
-struct Day4Part1Solver {
+impl AdventDay for Day4 {
+    type Data = String;
+
+    fn parse(input: &str) -> Result<Self::Data, ParseError> {
+        Ok(input.to_owned())
+    }
+}
+
+pub struct Day4Part1Solver {
     data: String,
 }
 
+#[common::advent_solver(day = 4, part = 1)]
 impl AdventSolver<usize, String> for Day4Part1Solver {
-    fn parse(input: &str) -> Self {
-        Day4Part1Solver {
-            data: input.to_owned(),
-        }
+    type Day = Day4;
+
+    fn from_data(data: String) -> Self {
+        Day4Part1Solver { data }
     }
 
-    fn solve(&self) -> common::AdventSolution<usize> {
+    fn solve(&mut self) -> common::AdventSolution<usize> {
         static WORD: [char; 4] = ['X', 'M', 'A', 'S'];
 
         let lines: Vec<Vec<char>> = self
@@ -44,18 +52,19 @@ impl AdventSolver<usize, String> for Day4Part1Solver {
     }
 }
 
-struct Day4Part2Solver {
+pub struct Day4Part2Solver {
     data: String,
 }
 
+#[common::advent_solver(day = 4, part = 2)]
 impl AdventSolver<usize, String> for Day4Part2Solver {
-    fn parse(input: &str) -> Self {
-        Day4Part2Solver {
-            data: input.to_owned(),
-        }
+    type Day = Day4;
+
+    fn from_data(data: String) -> Self {
+        Day4Part2Solver { data }
     }
 
-    fn solve(&self) -> common::AdventSolution<usize> {
+    fn solve(&mut self) -> common::AdventSolution<usize> {
         static WORD: [char; 3] = ['M', 'A', 'S'];
 
         let lines: Vec<Vec<char>> = self
@@ -253,19 +262,6 @@ impl Direction {
             Direction::SouthEast => Direction::NorthWest,
         }
     }
-
-    pub fn flip_around_north(self) -> Direction {
-        match self {
-            Direction::North => Direction::North,
-            Direction::South => Direction::South,
-            Direction::East => Direction::West,
-            Direction::West => Direction::East,
-            Direction::NorthEast => Direction::NorthWest,
-            Direction::NorthWest => Direction::NorthEast,
-            Direction::SouthEast => Direction::SouthWest,
-            Direction::SouthWest => Direction::SouthEast,
-        }
-    }
 }
 
 fn count_matches(word: &[char], lines: &Vec<Vec<char>>, line_idx: usize, char_idx: usize) -> usize {
@@ -335,56 +331,9 @@ fn match_word_in_direction(
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_day_4_part_1_example() {
-        // let input = common::read_example_input(1);
-        let input = include_str!("../res/inputs/example_input_p1");
-        let solver = Day4Part1Solver::parse(input);
-        let solution = solver.solve();
-        solution.prove(&18);
-    }
-
-    #[test]
-    fn test_day_4_part_1() {
-        let input = common::read_input();
-        let solver = Day4Part1Solver::parse(&input);
-        let solution = solver.solve();
-        solution.prove(&2517);
-    }
-
-    #[bench]
-    fn bench_day_4_part_1(bencher: &mut test::Bencher) {
-        let input = common::read_input();
-        let solver = Day4Part1Solver::parse(&input);
-        bencher.iter(|| {
-            let solution = solver.solve();
-            solution.prove(&2517);
-        });
-    }
-
-    #[test]
-    fn test_day_4_part_2_example() {
-        let input = include_str!("../res/inputs/example_input_p2");
-        let solver = Day4Part2Solver::parse(input);
-        let solution = solver.solve();
-        solution.prove(&9);
-    }
-
-    #[test]
-    fn test_day_4_part_2() {
-        let input = common::read_input();
-        let solver = Day4Part2Solver::parse(&input);
-        let solution = solver.solve();
-        solution.prove(&1960);
-    }
-
-    #[bench]
-    fn bench_day_4_part_2(bencher: &mut test::Bencher) {
-        let input = common::read_input();
-        let solver = Day4Part2Solver::parse(&input);
-        bencher.iter(|| {
-            let solution = solver.solve();
-            solution.prove(&1960);
-        });
+    common::advent_tests! {
+        day: 4,
+        part_1: Day4Part1Solver,
+        part_2: Day4Part2Solver,
     }
 }
@@ -1,11 +1,72 @@
 #![allow(clippy::cargo_common_metadata)]
-#![feature(test)]
 
-extern crate test;
+use common::{AdventDay, AdventSolver, ParseError};
 
-use common::AdventSolver;
+pub struct Day5;
+
+impl AdventDay for Day5 {
+    type Data = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Data, ParseError> {
+        Ok(input.lines().map(str::to_owned).collect())
+    }
+}
+
+pub struct Day5Part1Solver {
+    data: Vec<String>,
+}
+
+#[common::advent_solver(day = 5, part = 1)]
+impl AdventSolver<usize, Vec<String>> for Day5Part1Solver {
+    type Day = Day5;
+
+    // Remove once the part is solved.
+    const PLACEHOLDER: bool = true;
+
+    fn from_data(data: Vec<String>) -> Self {
+        Self { data }
+    }
+
+    fn solve(&mut self) -> common::AdventSolution<usize> {
+        0.into()
+    }
+
+    fn data(&self) -> &Vec<String> {
+        &self.data
+    }
+}
+
+pub struct Day5Part2Solver {
+    data: Vec<String>,
+}
+
+#[common::advent_solver(day = 5, part = 2)]
+impl AdventSolver<usize, Vec<String>> for Day5Part2Solver {
+    type Day = Day5;
+
+    // Remove once the part is solved.
+    const PLACEHOLDER: bool = true;
+
+    fn from_data(data: Vec<String>) -> Self {
+        Self { data }
+    }
+
+    fn solve(&mut self) -> common::AdventSolution<usize> {
+        0.into()
+    }
+
+    fn data(&self) -> &Vec<String> {
+        &self.data
+    }
+}
 
 #[cfg(test)]
 mod tests {
     use super::*;
+
+    common::advent_tests! {
+        day: 5,
+        part_1: Day5Part1Solver,
+        part_2: Day5Part2Solver,
+    }
 }